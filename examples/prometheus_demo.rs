@@ -6,7 +6,7 @@
 use nfs_gaze::{
     cli::{Args, parse_operations_filter},
     metrics::{MetricsConfig, MetricsManager},
-    types::{NFSMount, DeltaStats},
+    types::{NFSMount, DeltaStats, NFSServerCaps},
 };
 use std::collections::HashMap;
 
@@ -19,6 +19,9 @@ fn main() {
         otel_endpoint: None,
         export_interval: std::time::Duration::from_secs(10),
         include_labels: true,
+        enable_graphite: false,
+        graphite_endpoint: None,
+        metric_prefix: "nfs_gaze".to_string(),
     };
 
     // Create metrics manager
@@ -42,12 +45,14 @@ fn main() {
                         delta_queue: 100,
                         delta_errors: 2,
                         delta_retrans: 1,
+                        delta_ntrans: 100,
                         avg_rtt: 5.0,
                         avg_exec: 8.0,
                         avg_queue: 1.0,
                         kb_per_op: 10.0,
                         kb_per_sec: 1000.0,
                         iops: 100.0,
+                        reset_detected: false,
                     }
                 ];
 
@@ -61,6 +66,24 @@ fn main() {
                     events: None,
                     bytes_read: 10485760,
                     bytes_write: 20971520,
+                    direct_bytes_read: 0,
+                    direct_bytes_write: 0,
+                    server_bytes_read: 10485760,
+                    server_bytes_write: 20971520,
+                    read_pages: 2560,
+                    write_pages: 5120,
+                    mount_addr: None,
+                    server_caps: NFSServerCaps::default(),
+                    nfs_version: None,
+                    proto: None,
+                    xprt_proto: None,
+                    xprt_sends: 0,
+                    xprt_bklog_u: 0,
+                    xprt_retrans: 0,
+                    transport: None,
+                    options: None,
+                    fstype: None,
+                    statvers: nfs_gaze::types::MountstatsVersion::Unknown,
                 };
 
                 // Export metrics
@@ -0,0 +1,252 @@
+//! Threshold-based alerting over `DeltaStats`. A breach must persist for a
+//! configurable number of consecutive intervals before an alert fires, and
+//! the same number of clear intervals before it's reported cleared, so a
+//! single noisy poll doesn't flap an alert on and off.
+
+use crate::types::DeltaStats;
+use std::collections::{HashMap, HashSet};
+
+/// A `DeltaStats` field an `AlertRule` can threshold on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ThresholdMetric {
+    AvgRtt,
+    DeltaRetrans,
+    Iops,
+    KbPerSec,
+}
+
+impl ThresholdMetric {
+    pub(crate) fn value(self, stat: &DeltaStats) -> f64 {
+        match self {
+            ThresholdMetric::AvgRtt => stat.avg_rtt,
+            ThresholdMetric::DeltaRetrans => stat.delta_retrans as f64,
+            ThresholdMetric::Iops => stat.iops,
+            ThresholdMetric::KbPerSec => stat.kb_per_sec,
+        }
+    }
+}
+
+/// Direction of the threshold comparison.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Comparison {
+    GreaterThan,
+    LessThan,
+}
+
+/// A single alerting rule: a metric, a direction, a threshold, and how many
+/// consecutive intervals a breach (or recovery) must persist before it's
+/// reported. `operations` scopes the rule to specific operation names;
+/// `None` applies it to every operation in the interval's `DeltaStats`.
+#[derive(Debug, Clone)]
+pub struct AlertRule {
+    pub name: String,
+    pub metric: ThresholdMetric,
+    pub comparison: Comparison,
+    pub threshold: f64,
+    pub operations: Option<HashSet<String>>,
+    pub consecutive_intervals: usize,
+}
+
+impl AlertRule {
+    fn matches_operation(&self, operation: &str) -> bool {
+        match &self.operations {
+            Some(ops) => ops.contains(operation),
+            None => true,
+        }
+    }
+
+    fn breaches(&self, value: f64) -> bool {
+        match self.comparison {
+            Comparison::GreaterThan => value > self.threshold,
+            Comparison::LessThan => value < self.threshold,
+        }
+    }
+}
+
+/// Whether an `AlertEvent` represents a breach starting or a prior breach
+/// recovering.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AlertState {
+    Fired,
+    Cleared,
+}
+
+/// A debounced alert transition, emitted once when a rule's breach (or
+/// recovery) has persisted for its configured number of consecutive
+/// intervals.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AlertEvent {
+    pub rule_name: String,
+    pub operation: String,
+    pub metric_value: f64,
+    pub threshold: f64,
+    pub state: AlertState,
+}
+
+/// Per-(rule, operation) consecutive breach/clear counters, tracked across
+/// polls so a single noisy interval can't fire or clear an alert on its own.
+#[derive(Debug, Default)]
+struct BreachCounter {
+    consecutive_breach: usize,
+    consecutive_clear: usize,
+    fired: bool,
+}
+
+/// Evaluates a fixed set of `AlertRule`s against each poll's `DeltaStats`,
+/// debouncing both firing and clearing.
+pub struct AlertEvaluator {
+    rules: Vec<AlertRule>,
+    state: HashMap<(String, String), BreachCounter>,
+}
+
+impl AlertEvaluator {
+    pub fn new(rules: Vec<AlertRule>) -> Self {
+        Self {
+            rules,
+            state: HashMap::new(),
+        }
+    }
+
+    /// Evaluate this interval's `DeltaStats` against every rule, returning
+    /// the alert transitions (fires and clears) that just crossed their
+    /// debounce threshold. Most intervals return an empty `Vec`.
+    pub fn evaluate(&mut self, stats: &[DeltaStats]) -> Vec<AlertEvent> {
+        let mut events = Vec::new();
+
+        for rule in &self.rules {
+            for stat in stats {
+                if !rule.matches_operation(&stat.operation) {
+                    continue;
+                }
+
+                let value = rule.metric.value(stat);
+                let key = (rule.name.clone(), stat.operation.clone());
+                let counter = self.state.entry(key).or_default();
+                let required = rule.consecutive_intervals.max(1);
+
+                if rule.breaches(value) {
+                    counter.consecutive_breach += 1;
+                    counter.consecutive_clear = 0;
+                    if !counter.fired && counter.consecutive_breach >= required {
+                        counter.fired = true;
+                        events.push(AlertEvent {
+                            rule_name: rule.name.clone(),
+                            operation: stat.operation.clone(),
+                            metric_value: value,
+                            threshold: rule.threshold,
+                            state: AlertState::Fired,
+                        });
+                    }
+                } else {
+                    counter.consecutive_clear += 1;
+                    counter.consecutive_breach = 0;
+                    if counter.fired && counter.consecutive_clear >= required {
+                        counter.fired = false;
+                        events.push(AlertEvent {
+                            rule_name: rule.name.clone(),
+                            operation: stat.operation.clone(),
+                            metric_value: value,
+                            threshold: rule.threshold,
+                            state: AlertState::Cleared,
+                        });
+                    }
+                }
+            }
+        }
+
+        events
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_test_stat(operation: &str, avg_rtt: f64) -> DeltaStats {
+        DeltaStats {
+            operation: operation.to_string(),
+            delta_ops: 10,
+            delta_bytes: 0,
+            delta_sent: 0,
+            delta_recv: 0,
+            delta_rtt: 0,
+            delta_exec: 0,
+            delta_queue: 0,
+            delta_errors: 0,
+            delta_retrans: 0,
+            delta_ntrans: 0,
+            avg_rtt,
+            avg_exec: 0.0,
+            avg_queue: 0.0,
+            kb_per_op: 0.0,
+            kb_per_sec: 0.0,
+            iops: 10.0,
+            reset_detected: false,
+        }
+    }
+
+    fn rtt_rule(threshold: f64, consecutive_intervals: usize) -> AlertRule {
+        AlertRule {
+            name: "high-rtt".to_string(),
+            metric: ThresholdMetric::AvgRtt,
+            comparison: Comparison::GreaterThan,
+            threshold,
+            operations: None,
+            consecutive_intervals,
+        }
+    }
+
+    #[test]
+    fn test_single_interval_spike_does_not_fire() {
+        let mut evaluator = AlertEvaluator::new(vec![rtt_rule(50.0, 3)]);
+
+        let events = evaluator.evaluate(&[create_test_stat("READ", 100.0)]);
+        assert!(events.is_empty());
+
+        // Back below threshold before the breach ever persisted.
+        let events = evaluator.evaluate(&[create_test_stat("READ", 10.0)]);
+        assert!(events.is_empty());
+    }
+
+    #[test]
+    fn test_sustained_breach_fires_once() {
+        let mut evaluator = AlertEvaluator::new(vec![rtt_rule(50.0, 3)]);
+
+        assert!(evaluator.evaluate(&[create_test_stat("READ", 100.0)]).is_empty());
+        assert!(evaluator.evaluate(&[create_test_stat("READ", 100.0)]).is_empty());
+        let events = evaluator.evaluate(&[create_test_stat("READ", 100.0)]);
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].state, AlertState::Fired);
+
+        // Staying breached afterward does not refire.
+        let events = evaluator.evaluate(&[create_test_stat("READ", 100.0)]);
+        assert!(events.is_empty());
+    }
+
+    #[test]
+    fn test_recovery_clears_after_sustained_improvement() {
+        let mut evaluator = AlertEvaluator::new(vec![rtt_rule(50.0, 2)]);
+
+        evaluator.evaluate(&[create_test_stat("READ", 100.0)]);
+        let fired = evaluator.evaluate(&[create_test_stat("READ", 100.0)]);
+        assert_eq!(fired[0].state, AlertState::Fired);
+
+        // One good interval alone shouldn't clear it yet.
+        assert!(evaluator.evaluate(&[create_test_stat("READ", 10.0)]).is_empty());
+        let cleared = evaluator.evaluate(&[create_test_stat("READ", 10.0)]);
+        assert_eq!(cleared.len(), 1);
+        assert_eq!(cleared[0].state, AlertState::Cleared);
+    }
+
+    #[test]
+    fn test_rule_scoped_to_specific_operations_ignores_others() {
+        let rule = AlertRule {
+            operations: Some(["READ".to_string()].into_iter().collect()),
+            ..rtt_rule(50.0, 1)
+        };
+        let mut evaluator = AlertEvaluator::new(vec![rule]);
+
+        let events = evaluator.evaluate(&[create_test_stat("WRITE", 999.0)]);
+        assert!(events.is_empty());
+    }
+}
@@ -1,6 +1,21 @@
-use clap::Parser;
+use clap::{Parser, ValueEnum};
 use std::collections::HashSet;
 
+/// Output rendering mode, selected by `--output`.
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum OutputFormat {
+    /// Human-readable table (the default interactive view)
+    #[default]
+    Table,
+    /// One self-contained JSON object per line (JSONL)
+    Json,
+    /// CSV with a single header row followed by data rows
+    Csv,
+    /// One JSON object per poll interval, with every operation's
+    /// `DeltaStats` nested as an `"operations"` array
+    NdJson,
+}
+
 #[derive(Parser, Debug)]
 #[command(name = "nfs-gaze")]
 #[command(about = "NFS I/O Statistics Monitor")]
@@ -19,6 +34,18 @@ Examples:
 
   # Clear screen between iterations
   nfs-gaze -m /mnt/nfs --clear
+
+  # Show negotiated server capability flags
+  nfs-gaze -m /mnt/nfs --caps
+
+  # Classic nfs-iostat column layout
+  nfs-gaze -m /mnt/nfs --iostat
+
+  # Sample attribute-cache stats every 30s while I/O stats update every 1s
+  nfs-gaze -m /mnt/nfs --attr --attr-interval 30
+
+  # Stream JSON Lines for ingestion by a metrics pipeline
+  nfs-gaze -m /mnt/nfs --output json
 "#)]
 pub struct Args {
     /// Mount point to monitor
@@ -41,10 +68,33 @@ pub struct Args {
     #[arg(long = "attr")]
     pub show_attr: bool,
 
+    /// Sampling interval in seconds for attribute-cache statistics.
+    /// Defaults to 5x the base interval.
+    #[arg(long = "attr-interval")]
+    pub attr_interval: Option<u64>,
+
+    /// Show negotiated server capability flags
+    #[arg(long = "caps")]
+    pub show_caps: bool,
+
+    /// Sampling interval in seconds for capability re-checks. Defaults to
+    /// 10x the base interval.
+    #[arg(long = "caps-interval")]
+    pub caps_interval: Option<u64>,
+
     /// Show bandwidth statistics
     #[arg(long = "bw")]
     pub show_bandwidth: bool,
 
+    /// Display output in classic nfs-iostat column layout
+    #[arg(long = "iostat")]
+    pub iostat: bool,
+
+    /// Output format: table, json (JSONL), csv, or nd-json (one JSON object
+    /// per poll interval)
+    #[arg(long = "output", value_enum, default_value_t = OutputFormat::Table)]
+    pub output: OutputFormat,
+
     /// Clear screen between iterations
     #[arg(long = "clear")]
     pub clear_screen: bool,
@@ -1,4 +1,6 @@
-use crate::types::{DeltaStats, NFSMount, Result};
+use crate::alerts::{AlertEvent, AlertState};
+use crate::stats::TransportStats;
+use crate::types::{DeltaStats, EventDeltaStats, NFSMount, Result};
 use chrono::{DateTime, Utc};
 use std::io::Write;
 
@@ -45,13 +47,20 @@ pub fn display_stats_simple<W: Write>(
         writeln!(writer, "{}", "-".repeat(48))?;
     }
 
-    // Write data rows
+    // Write data rows. A counter reset/wraparound this interval is flagged
+    // with a trailing "*" on the operation name rather than silently shown
+    // as a (likely corrected, but still suspect) rate.
     for stat in stats {
+        let op_label = if stat.reset_detected {
+            format!("{}*", stat.operation)
+        } else {
+            stat.operation.clone()
+        };
         if show_bandwidth {
             writeln!(
                 writer,
                 "{:<12} {:>8} {:>8} {:>8} {:>8} {:>8} {:>8}",
-                stat.operation,
+                op_label,
                 format_rate(stat.iops),
                 format_duration(stat.avg_rtt as i64),
                 format_duration(stat.avg_exec as i64),
@@ -63,7 +72,7 @@ pub fn display_stats_simple<W: Write>(
             writeln!(
                 writer,
                 "{:<12} {:>8} {:>8} {:>8} {:>8}",
-                stat.operation,
+                op_label,
                 format_rate(stat.iops),
                 format_duration(stat.avg_rtt as i64),
                 format_duration(stat.avg_exec as i64),
@@ -72,7 +81,290 @@ pub fn display_stats_simple<W: Write>(
         }
     }
 
+    if stats.iter().any(|s| s.reset_detected) {
+        writeln!(writer, "* counter reset or wraparound detected this interval")?;
+    }
+
+    writeln!(writer)?;
+    Ok(())
+}
+
+/// Display statistics in the column layout of the classic `nfs-iostat`
+/// tool: per-mount ops/s and average RPC backlog length, followed by a
+/// read/write breakdown of throughput, retransmits, and latency.
+pub fn display_stats_iostat<W: Write>(
+    writer: &mut W,
+    mount: &NFSMount,
+    stats: &[DeltaStats],
+    transport: &TransportStats,
+    timestamp: &DateTime<Utc>,
+) -> Result<()> {
+    writeln!(writer, "{} mounted on {}:", mount.device, mount.mount_point)?;
+    writeln!(
+        writer,
+        "Timestamp: {}",
+        timestamp.format("%Y-%m-%d %H:%M:%S UTC")
+    )?;
+    writeln!(writer)?;
+
+    writeln!(
+        writer,
+        "{:>8} {:>10}",
+        "ops/s", "rpc bklog"
+    )?;
+    writeln!(
+        writer,
+        "{:>8} {:>10}",
+        format_rate(transport.ops_per_sec),
+        format_rate(transport.backlog_avg)
+    )?;
     writeln!(writer)?;
+
+    for op_name in ["READ", "WRITE"] {
+        let Some(stat) = stats.iter().find(|s| s.operation == op_name) else {
+            continue;
+        };
+
+        writeln!(writer, "{}:", op_name.to_lowercase())?;
+        writeln!(
+            writer,
+            "{:>10} {:>10} {:>10} {:>16} {:>14} {:>14}",
+            "ops/s", "kB/s", "kB/op", "retrans", "avg RTT (ms)", "avg exe (ms)"
+        )?;
+        writeln!(
+            writer,
+            "{:>10} {:>10} {:>10} {:>16} {:>14} {:>14}",
+            format_rate(stat.iops),
+            format_rate(stat.kb_per_sec),
+            format_rate(stat.kb_per_op),
+            format!(
+                "{} ({:.1}%)",
+                stat.delta_retrans,
+                if stat.delta_ntrans > 0 {
+                    stat.delta_retrans as f64 / stat.delta_ntrans as f64 * 100.0
+                } else {
+                    0.0
+                }
+            ),
+            format_duration(stat.avg_rtt as i64),
+            format_duration(stat.avg_exec as i64)
+        )?;
+        writeln!(writer)?;
+    }
+
+    Ok(())
+}
+
+/// Display a compact attribute-cache efficiency block: how much of the
+/// inode-attribute traffic over the interval was served from cache versus
+/// invalidated and re-fetched, plus the VFS call volume driving it.
+pub fn display_attr_stats<W: Write>(
+    writer: &mut W,
+    mount: &NFSMount,
+    events: &EventDeltaStats,
+) -> Result<()> {
+    writeln!(writer, "Attribute cache ({}):", mount.mount_point)?;
+    writeln!(
+        writer,
+        "  getattr cache hit%: {}",
+        format_rate(events.attr_cache_hit_pct)
+    )?;
+    writeln!(
+        writer,
+        "  inode revalidations: {}  attr invalidations: {}  data invalidations: {}",
+        events.inode_revalidate, events.attr_invalidate, events.data_invalidate
+    )?;
+    writeln!(
+        writer,
+        "  dentry revalidations: {}  vfs open: {}  vfs lookup: {}  vfs access: {}  readdir: {}",
+        events.dentry_revalidate,
+        events.vfs_open,
+        events.vfs_lookup,
+        events.vfs_access,
+        events.vfs_getdents
+    )?;
+    writeln!(writer)?;
+    Ok(())
+}
+
+/// Emit each `DeltaStats` row as a self-contained JSON object, one per
+/// line (JSONL), so the stream can be tailed and piped into a metrics
+/// pipeline. Numeric fields are raw values rather than the
+/// `format_rate`/`format_bandwidth`-truncated strings the table view uses.
+pub fn display_stats_json<W: Write>(
+    writer: &mut W,
+    mount: &NFSMount,
+    stats: &[DeltaStats],
+    timestamp: &DateTime<Utc>,
+) -> Result<()> {
+    let ts = timestamp.to_rfc3339();
+    for stat in stats {
+        writeln!(
+            writer,
+            "{{\"timestamp\":\"{}\",\"device\":\"{}\",\"mount_point\":\"{}\",\"server\":\"{}\",\"export\":\"{}\",\"operation\":\"{}\",\"iops\":{},\"avg_rtt\":{},\"avg_exec\":{},\"kb_per_sec\":{},\"kb_per_op\":{},\"delta_errors\":{}}}",
+            ts,
+            json_escape(&mount.device),
+            json_escape(&mount.mount_point),
+            json_escape(&mount.server),
+            json_escape(&mount.export),
+            json_escape(&stat.operation),
+            stat.iops,
+            stat.avg_rtt,
+            stat.avg_exec,
+            stat.kb_per_sec,
+            stat.kb_per_op,
+            stat.delta_errors
+        )?;
+    }
+    Ok(())
+}
+
+fn json_escape(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// Emit each `DeltaStats` row as a CSV record. The header row is written
+/// once; callers track that with `header_written` across calls.
+pub fn display_stats_csv<W: Write>(
+    writer: &mut W,
+    mount: &NFSMount,
+    stats: &[DeltaStats],
+    timestamp: &DateTime<Utc>,
+    header_written: &mut bool,
+) -> Result<()> {
+    if !*header_written {
+        writeln!(
+            writer,
+            "timestamp,device,mount_point,server,export,operation,iops,avg_rtt,avg_exec,kb_per_sec,kb_per_op,delta_errors"
+        )?;
+        *header_written = true;
+    }
+
+    let ts = timestamp.to_rfc3339();
+    for stat in stats {
+        writeln!(
+            writer,
+            "{},{},{},{},{},{},{},{},{},{},{},{}",
+            ts,
+            mount.device,
+            mount.mount_point,
+            mount.server,
+            mount.export,
+            stat.operation,
+            stat.iops,
+            stat.avg_rtt,
+            stat.avg_exec,
+            stat.kb_per_sec,
+            stat.kb_per_op,
+            stat.delta_errors
+        )?;
+    }
+    Ok(())
+}
+
+/// Emit one NDJSON (newline-delimited JSON) object per poll interval: the
+/// mount identity and timestamp, plus every operation's `DeltaStats` nested
+/// as an `"operations"` array in a single line. This is the per-interval
+/// sibling of `display_stats_json`'s per-operation rows, for consumers that
+/// want one self-contained record per poll rather than one per operation
+/// (e.g. log shippers that tail and parse a line at a time). Writes nothing
+/// for an empty `stats` slice, so an idle mount produces no output line.
+///
+/// Named `display_stats_ndjson` rather than `display_stats_json` because
+/// that name was already taken by the per-operation emitter wired to
+/// `--output json`; wired into the CLI as `--output nd-json`.
+pub fn display_stats_ndjson<W: Write>(
+    writer: &mut W,
+    mount: &NFSMount,
+    stats: &[DeltaStats],
+    show_bandwidth: bool,
+    timestamp: &DateTime<Utc>,
+) -> Result<()> {
+    if stats.is_empty() {
+        return Ok(());
+    }
+
+    let operations: Vec<String> = stats
+        .iter()
+        .map(|stat| {
+            if show_bandwidth {
+                format!(
+                    "{{\"operation\":\"{}\",\"iops\":{},\"avg_rtt\":{},\"avg_exec\":{},\"kb_per_sec\":{},\"kb_per_op\":{},\"delta_errors\":{}}}",
+                    json_escape(&stat.operation),
+                    stat.iops,
+                    stat.avg_rtt,
+                    stat.avg_exec,
+                    stat.kb_per_sec,
+                    stat.kb_per_op,
+                    stat.delta_errors
+                )
+            } else {
+                format!(
+                    "{{\"operation\":\"{}\",\"iops\":{},\"avg_rtt\":{},\"avg_exec\":{},\"delta_errors\":{}}}",
+                    json_escape(&stat.operation),
+                    stat.iops,
+                    stat.avg_rtt,
+                    stat.avg_exec,
+                    stat.delta_errors
+                )
+            }
+        })
+        .collect();
+
+    writeln!(
+        writer,
+        "{{\"timestamp\":\"{}\",\"device\":\"{}\",\"mount_point\":\"{}\",\"server\":\"{}\",\"export\":\"{}\",\"operations\":[{}]}}",
+        timestamp.to_rfc3339(),
+        json_escape(&mount.device),
+        json_escape(&mount.mount_point),
+        json_escape(&mount.server),
+        json_escape(&mount.export),
+        operations.join(",")
+    )?;
+
+    Ok(())
+}
+
+/// Render a debounced `AlertEvent` as a single human-readable line.
+pub fn display_alert_event<W: Write>(writer: &mut W, event: &AlertEvent) -> Result<()> {
+    match event.state {
+        AlertState::Fired => writeln!(
+            writer,
+            "ALERT [{}] {}: {:.2} breached threshold {:.2}",
+            event.rule_name, event.operation, event.metric_value, event.threshold
+        )?,
+        AlertState::Cleared => writeln!(
+            writer,
+            "CLEARED [{}] {}: {:.2} back within threshold {:.2}",
+            event.rule_name, event.operation, event.metric_value, event.threshold
+        )?,
+    }
+    Ok(())
+}
+
+/// Render a debounced `AlertEvent` as a single-line JSON object, mirroring
+/// the shape of `display_stats_json`'s per-operation rows.
+pub fn display_alert_event_json<W: Write>(
+    writer: &mut W,
+    event: &AlertEvent,
+    timestamp: &DateTime<Utc>,
+) -> Result<()> {
+    let state = match event.state {
+        AlertState::Fired => "fired",
+        AlertState::Cleared => "cleared",
+    };
+
+    writeln!(
+        writer,
+        "{{\"timestamp\":\"{}\",\"rule\":\"{}\",\"operation\":\"{}\",\"state\":\"{}\",\"value\":{},\"threshold\":{}}}",
+        timestamp.to_rfc3339(),
+        json_escape(&event.rule_name),
+        json_escape(&event.operation),
+        state,
+        event.metric_value,
+        event.threshold
+    )?;
+
     Ok(())
 }
 
@@ -107,7 +399,7 @@ pub fn format_bandwidth(kb_per_sec: f64) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::types::NFSMount;
+    use crate::types::{NFSMount, NFSServerCaps};
     use chrono::TimeZone;
     use std::collections::HashMap;
 
@@ -148,6 +440,24 @@ mod tests {
             events: None,
             bytes_read: 0,
             bytes_write: 0,
+            direct_bytes_read: 0,
+            direct_bytes_write: 0,
+            server_bytes_read: 0,
+            server_bytes_write: 0,
+            read_pages: 0,
+            write_pages: 0,
+            mount_addr: None,
+            server_caps: NFSServerCaps::default(),
+            nfs_version: None,
+            proto: None,
+            xprt_proto: None,
+            xprt_sends: 0,
+            xprt_bklog_u: 0,
+            xprt_retrans: 0,
+            transport: None,
+            options: None,
+            fstype: None,
+            statvers: crate::types::MountstatsVersion::Unknown,
         };
 
         let stats = vec![];
@@ -160,6 +470,417 @@ mod tests {
         assert!(output.is_empty());
     }
 
+    #[test]
+    fn test_display_stats_iostat() {
+        let mount = NFSMount {
+            device: "server:/export".to_string(),
+            mount_point: "/mnt/nfs".to_string(),
+            server: "server".to_string(),
+            export: "/export".to_string(),
+            age: 0,
+            operations: HashMap::new(),
+            events: None,
+            bytes_read: 0,
+            bytes_write: 0,
+            direct_bytes_read: 0,
+            direct_bytes_write: 0,
+            server_bytes_read: 0,
+            server_bytes_write: 0,
+            read_pages: 0,
+            write_pages: 0,
+            mount_addr: None,
+            server_caps: NFSServerCaps::default(),
+            nfs_version: None,
+            proto: None,
+            xprt_proto: None,
+            xprt_sends: 0,
+            xprt_bklog_u: 0,
+            xprt_retrans: 0,
+            transport: None,
+            options: None,
+            fstype: None,
+            statvers: crate::types::MountstatsVersion::Unknown,
+        };
+
+        let stats = vec![DeltaStats {
+            operation: "READ".to_string(),
+            delta_ops: 100,
+            delta_bytes: 1024,
+            delta_sent: 512,
+            delta_recv: 512,
+            delta_rtt: 1000,
+            delta_exec: 2000,
+            delta_queue: 0,
+            delta_errors: 0,
+            delta_retrans: 2,
+            delta_ntrans: 100,
+            avg_rtt: 10.0,
+            avg_exec: 20.0,
+            avg_queue: 0.0,
+            kb_per_op: 0.01,
+            kb_per_sec: 1.0,
+            iops: 100.0,
+            reset_detected: false,
+        }];
+
+        let transport = crate::stats::TransportStats {
+            ops_per_sec: 100.0,
+            backlog_avg: 0.5,
+            retrans_pct: 2.0,
+        };
+
+        let timestamp = Utc.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap();
+        let mut writer = MockWriter::new();
+
+        display_stats_iostat(&mut writer, &mount, &stats, &transport, &timestamp).unwrap();
+        let output = writer.to_string();
+
+        assert!(output.contains("read:"));
+        assert!(!output.contains("write:"));
+        assert!(output.contains("2 (2.0%)"));
+    }
+
+    fn create_test_stat() -> DeltaStats {
+        DeltaStats {
+            operation: "READ".to_string(),
+            delta_ops: 100,
+            delta_bytes: 1024,
+            delta_sent: 512,
+            delta_recv: 512,
+            delta_rtt: 1000,
+            delta_exec: 2000,
+            delta_queue: 0,
+            delta_errors: 2,
+            delta_retrans: 1,
+            delta_ntrans: 100,
+            avg_rtt: 10.0,
+            avg_exec: 20.0,
+            avg_queue: 0.0,
+            kb_per_op: 0.5,
+            kb_per_sec: 50.0,
+            iops: 100.0,
+            reset_detected: false,
+        }
+    }
+
+    #[test]
+    fn test_display_stats_json() {
+        let mount = NFSMount {
+            device: "server:/export".to_string(),
+            mount_point: "/mnt/nfs".to_string(),
+            server: "server".to_string(),
+            export: "/export".to_string(),
+            age: 0,
+            operations: HashMap::new(),
+            events: None,
+            bytes_read: 0,
+            bytes_write: 0,
+            direct_bytes_read: 0,
+            direct_bytes_write: 0,
+            server_bytes_read: 0,
+            server_bytes_write: 0,
+            read_pages: 0,
+            write_pages: 0,
+            mount_addr: None,
+            server_caps: NFSServerCaps::default(),
+            nfs_version: None,
+            proto: None,
+            xprt_proto: None,
+            xprt_sends: 0,
+            xprt_bklog_u: 0,
+            xprt_retrans: 0,
+            transport: None,
+            options: None,
+            fstype: None,
+            statvers: crate::types::MountstatsVersion::Unknown,
+        };
+
+        let stats = vec![create_test_stat()];
+        let timestamp = Utc.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap();
+        let mut writer = MockWriter::new();
+
+        display_stats_json(&mut writer, &mount, &stats, &timestamp).unwrap();
+        let output = writer.to_string();
+
+        assert_eq!(output.lines().count(), 1);
+        assert!(output.contains("\"operation\":\"READ\""));
+        assert!(output.contains("\"iops\":100"));
+        assert!(output.contains("\"mount_point\":\"/mnt/nfs\""));
+        assert!(output.contains("\"timestamp\":\"2024-01-01T12:00:00+00:00\""));
+    }
+
+    #[test]
+    fn test_display_stats_csv_writes_header_once() {
+        let mount = NFSMount {
+            device: "server:/export".to_string(),
+            mount_point: "/mnt/nfs".to_string(),
+            server: "server".to_string(),
+            export: "/export".to_string(),
+            age: 0,
+            operations: HashMap::new(),
+            events: None,
+            bytes_read: 0,
+            bytes_write: 0,
+            direct_bytes_read: 0,
+            direct_bytes_write: 0,
+            server_bytes_read: 0,
+            server_bytes_write: 0,
+            read_pages: 0,
+            write_pages: 0,
+            mount_addr: None,
+            server_caps: NFSServerCaps::default(),
+            nfs_version: None,
+            proto: None,
+            xprt_proto: None,
+            xprt_sends: 0,
+            xprt_bklog_u: 0,
+            xprt_retrans: 0,
+            transport: None,
+            options: None,
+            fstype: None,
+            statvers: crate::types::MountstatsVersion::Unknown,
+        };
+
+        let stats = vec![create_test_stat()];
+        let timestamp = Utc.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap();
+        let mut writer = MockWriter::new();
+        let mut header_written = false;
+
+        display_stats_csv(&mut writer, &mount, &stats, &timestamp, &mut header_written).unwrap();
+        display_stats_csv(&mut writer, &mount, &stats, &timestamp, &mut header_written).unwrap();
+
+        let output = writer.to_string();
+        assert_eq!(output.matches("timestamp,device").count(), 1);
+        assert_eq!(output.matches("/mnt/nfs").count(), 2);
+        assert!(header_written);
+    }
+
+    #[test]
+    fn test_display_stats_ndjson_valid_standalone_json_per_line() {
+        let mount = NFSMount {
+            device: "server:/export".to_string(),
+            mount_point: "/mnt/nfs".to_string(),
+            server: "server".to_string(),
+            export: "/export".to_string(),
+            age: 0,
+            operations: HashMap::new(),
+            events: None,
+            bytes_read: 0,
+            bytes_write: 0,
+            direct_bytes_read: 0,
+            direct_bytes_write: 0,
+            server_bytes_read: 0,
+            server_bytes_write: 0,
+            read_pages: 0,
+            write_pages: 0,
+            mount_addr: None,
+            server_caps: NFSServerCaps::default(),
+            nfs_version: None,
+            proto: None,
+            xprt_proto: None,
+            xprt_sends: 0,
+            xprt_bklog_u: 0,
+            xprt_retrans: 0,
+            transport: None,
+            options: None,
+            fstype: None,
+            statvers: crate::types::MountstatsVersion::Unknown,
+        };
+
+        let stats = vec![create_test_stat()];
+        let timestamp = Utc.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap();
+        let mut writer = MockWriter::new();
+
+        display_stats_ndjson(&mut writer, &mount, &stats, false, &timestamp).unwrap();
+        let output = writer.to_string();
+
+        assert_eq!(output.lines().count(), 1);
+        let line = output.lines().next().unwrap();
+        assert!(line.starts_with('{') && line.ends_with('}'));
+        assert!(line.contains("\"mount_point\":\"/mnt/nfs\""));
+        assert!(line.contains("\"operations\":[{\"operation\":\"READ\""));
+        // With bandwidth disabled, byte-rate fields are left out of the
+        // nested per-operation objects.
+        assert!(!line.contains("kb_per_sec"));
+    }
+
+    #[test]
+    fn test_display_stats_ndjson_with_bandwidth() {
+        let mount = NFSMount {
+            device: "server:/export".to_string(),
+            mount_point: "/mnt/nfs".to_string(),
+            server: "server".to_string(),
+            export: "/export".to_string(),
+            age: 0,
+            operations: HashMap::new(),
+            events: None,
+            bytes_read: 0,
+            bytes_write: 0,
+            direct_bytes_read: 0,
+            direct_bytes_write: 0,
+            server_bytes_read: 0,
+            server_bytes_write: 0,
+            read_pages: 0,
+            write_pages: 0,
+            mount_addr: None,
+            server_caps: NFSServerCaps::default(),
+            nfs_version: None,
+            proto: None,
+            xprt_proto: None,
+            xprt_sends: 0,
+            xprt_bklog_u: 0,
+            xprt_retrans: 0,
+            transport: None,
+            options: None,
+            fstype: None,
+            statvers: crate::types::MountstatsVersion::Unknown,
+        };
+
+        let stats = vec![create_test_stat()];
+        let timestamp = Utc.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap();
+        let mut writer = MockWriter::new();
+
+        display_stats_ndjson(&mut writer, &mount, &stats, true, &timestamp).unwrap();
+        let output = writer.to_string();
+
+        assert!(output.contains("\"kb_per_sec\""));
+    }
+
+    #[test]
+    fn test_display_stats_ndjson_empty_stats_produce_no_output() {
+        let mount = NFSMount {
+            device: "server:/export".to_string(),
+            mount_point: "/mnt/nfs".to_string(),
+            server: "server".to_string(),
+            export: "/export".to_string(),
+            age: 0,
+            operations: HashMap::new(),
+            events: None,
+            bytes_read: 0,
+            bytes_write: 0,
+            direct_bytes_read: 0,
+            direct_bytes_write: 0,
+            server_bytes_read: 0,
+            server_bytes_write: 0,
+            read_pages: 0,
+            write_pages: 0,
+            mount_addr: None,
+            server_caps: NFSServerCaps::default(),
+            nfs_version: None,
+            proto: None,
+            xprt_proto: None,
+            xprt_sends: 0,
+            xprt_bklog_u: 0,
+            xprt_retrans: 0,
+            transport: None,
+            options: None,
+            fstype: None,
+            statvers: crate::types::MountstatsVersion::Unknown,
+        };
+
+        let timestamp = Utc.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap();
+        let mut writer = MockWriter::new();
+
+        display_stats_ndjson(&mut writer, &mount, &[], false, &timestamp).unwrap();
+        assert!(writer.to_string().is_empty());
+    }
+
+    #[test]
+    fn test_display_alert_event_fired_and_cleared_lines() {
+        let mut writer = MockWriter::new();
+        let fired = AlertEvent {
+            rule_name: "high-rtt".to_string(),
+            operation: "READ".to_string(),
+            metric_value: 123.45,
+            threshold: 50.0,
+            state: AlertState::Fired,
+        };
+        let cleared = AlertEvent {
+            state: AlertState::Cleared,
+            ..fired.clone()
+        };
+
+        display_alert_event(&mut writer, &fired).unwrap();
+        display_alert_event(&mut writer, &cleared).unwrap();
+
+        let output = writer.to_string();
+        assert!(output.contains("ALERT [high-rtt] READ: 123.45 breached threshold 50.00"));
+        assert!(output.contains("CLEARED [high-rtt] READ: 123.45 back within threshold 50.00"));
+    }
+
+    #[test]
+    fn test_display_alert_event_json() {
+        let mut writer = MockWriter::new();
+        let event = AlertEvent {
+            rule_name: "high-rtt".to_string(),
+            operation: "READ".to_string(),
+            metric_value: 123.45,
+            threshold: 50.0,
+            state: AlertState::Fired,
+        };
+        let timestamp = Utc.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap();
+
+        display_alert_event_json(&mut writer, &event, &timestamp).unwrap();
+        let output = writer.to_string();
+
+        assert!(output.contains("\"rule\":\"high-rtt\""));
+        assert!(output.contains("\"state\":\"fired\""));
+        assert!(output.contains("\"value\":123.45"));
+    }
+
+    #[test]
+    fn test_display_attr_stats() {
+        let mount = NFSMount {
+            device: "server:/export".to_string(),
+            mount_point: "/mnt/nfs".to_string(),
+            server: "server".to_string(),
+            export: "/export".to_string(),
+            age: 0,
+            operations: HashMap::new(),
+            events: None,
+            bytes_read: 0,
+            bytes_write: 0,
+            direct_bytes_read: 0,
+            direct_bytes_write: 0,
+            server_bytes_read: 0,
+            server_bytes_write: 0,
+            read_pages: 0,
+            write_pages: 0,
+            mount_addr: None,
+            server_caps: NFSServerCaps::default(),
+            nfs_version: None,
+            proto: None,
+            xprt_proto: None,
+            xprt_sends: 0,
+            xprt_bklog_u: 0,
+            xprt_retrans: 0,
+            transport: None,
+            options: None,
+            fstype: None,
+            statvers: crate::types::MountstatsVersion::Unknown,
+        };
+
+        let events = EventDeltaStats {
+            attr_invalidate: 5,
+            inode_revalidate: 50,
+            dentry_revalidate: 80,
+            data_invalidate: 8,
+            vfs_access: 200,
+            vfs_open: 25,
+            vfs_lookup: 45,
+            vfs_getdents: 4,
+            attr_cache_hit_pct: 75.0,
+        };
+
+        let mut writer = MockWriter::new();
+        display_attr_stats(&mut writer, &mount, &events).unwrap();
+        let output = writer.to_string();
+
+        assert!(output.contains("Attribute cache (/mnt/nfs)"));
+        assert!(output.contains("getattr cache hit%: 75.0"));
+        assert!(output.contains("inode revalidations: 50"));
+    }
+
     #[test]
     fn test_format_duration() {
         assert_eq!(format_duration(0), "0.0ms");
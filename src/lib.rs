@@ -1,6 +1,10 @@
+pub mod alerts;
 pub mod cli;
 pub mod display;
+pub mod metrics;
 pub mod monitor;
+#[cfg(target_os = "linux")]
+pub mod network;
 pub mod parser;
 pub mod stats;
 pub mod types;
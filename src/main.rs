@@ -67,10 +67,13 @@ fn run_linux() -> anyhow::Result<()> {
         &args.mount_point,
         &monitor_mounts,
         &operations_filter,
+        args.show_caps,
     )?;
 
     // Convert interval from seconds to Duration
     let interval = Duration::from_secs(args.interval);
+    let attr_interval = Duration::from_secs(args.attr_interval.unwrap_or(args.interval * 5));
+    let caps_interval = Duration::from_secs(args.caps_interval.unwrap_or(args.interval * 10));
 
     // Start monitoring loop
     if let Err(e) = monitor.monitoring_loop(
@@ -79,9 +82,15 @@ fn run_linux() -> anyhow::Result<()> {
         monitor_mounts,
         operations_filter,
         interval,
+        attr_interval,
+        caps_interval,
         args.count,
         args.show_bandwidth,
         args.clear_screen,
+        args.iostat,
+        args.show_attr,
+        args.show_caps,
+        args.output,
     ) {
         eprintln!("Monitoring error: {}", e);
         std::process::exit(1);
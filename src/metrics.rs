@@ -2,10 +2,32 @@ use crate::types::{DeltaStats, NFSMount, NFSEvents};
 use std::time::Duration;
 
 #[cfg(feature = "prometheus")]
-use prometheus::{Counter, Gauge, Histogram, Registry, Encoder, TextEncoder};
+use prometheus::{CounterVec, GaugeVec, HistogramVec, Registry, Encoder, TextEncoder};
 
+#[cfg(feature = "prometheus")]
+use std::io::{BufRead, BufReader, Write as IoWrite};
+#[cfg(feature = "prometheus")]
+use std::net::{TcpListener, TcpStream};
+#[cfg(feature = "prometheus")]
+use std::sync::atomic::{AtomicBool, Ordering};
+#[cfg(feature = "prometheus")]
+use std::sync::Arc;
+#[cfg(feature = "prometheus")]
+use std::thread::{self, JoinHandle};
+
+#[cfg(feature = "opentelemetry")]
+use opentelemetry::{global, metrics::*, KeyValue};
+#[cfg(feature = "opentelemetry")]
+use opentelemetry_otlp::{MetricExporter, WithExportConfig};
 #[cfg(feature = "opentelemetry")]
-use opentelemetry::{metrics::*, Context, KeyValue};
+use opentelemetry_sdk::metrics::{PeriodicReader, SdkMeterProvider};
+#[cfg(feature = "opentelemetry")]
+use std::sync::{Arc, Mutex};
+
+#[cfg(feature = "graphite")]
+use std::io::Write as GraphiteWrite;
+#[cfg(all(feature = "graphite", not(feature = "opentelemetry")))]
+use std::sync::Mutex;
 
 /// Metrics exporter trait for different backends
 pub trait MetricsExporter: Send + Sync {
@@ -15,6 +37,29 @@ pub trait MetricsExporter: Send + Sync {
     fn get_metrics_output(&self) -> Option<String>;
 }
 
+/// The VFS event counters as `(name, count)` pairs, shared by the Prometheus
+/// and Graphite backends so each VFS event type is reported as its own series
+/// instead of collapsing them into one counter. The OpenTelemetry backend
+/// does not use it and adds a subset of the events to a single counter.
+#[cfg(any(feature = "prometheus", feature = "graphite"))]
+fn vfs_event_pairs(events: &NFSEvents) -> [(&'static str, i64); 13] {
+    [
+        ("vfs_open", events.vfs_open),
+        ("vfs_lookup", events.vfs_lookup),
+        ("vfs_access", events.vfs_access),
+        ("vfs_read_page", events.vfs_read_page),
+        ("vfs_read_pages", events.vfs_read_pages),
+        ("vfs_write_page", events.vfs_write_page),
+        ("vfs_write_pages", events.vfs_write_pages),
+        ("vfs_getdents", events.vfs_getdents),
+        ("vfs_setattr", events.vfs_setattr),
+        ("vfs_flush", events.vfs_flush),
+        ("vfs_fsync", events.vfs_fsync),
+        ("vfs_lock", events.vfs_lock),
+        ("vfs_release", events.vfs_release),
+    ]
+}
+
 /// Configuration for metrics export
 #[derive(Debug, Clone)]
 pub struct MetricsConfig {
@@ -24,6 +69,9 @@ pub struct MetricsConfig {
     pub otel_endpoint: Option<String>,
     pub export_interval: Duration,
     pub include_labels: bool,
+    pub enable_graphite: bool,
+    pub graphite_endpoint: Option<String>,
+    pub metric_prefix: String,
 }
 
 impl Default for MetricsConfig {
@@ -35,81 +83,236 @@ impl Default for MetricsConfig {
             otel_endpoint: None,
             export_interval: Duration::from_secs(10),
             include_labels: true,
+            enable_graphite: false,
+            graphite_endpoint: None,
+            metric_prefix: "nfs_gaze".to_string(),
         }
     }
 }
 
+/// Labels applied to every per-operation NFS metric.
+#[cfg(feature = "prometheus")]
+const OPERATION_LABELS: &[&str] = &["mount_point", "server", "export", "mountaddr", "operation"];
+
+/// Labels applied to every VFS event metric.
+#[cfg(feature = "prometheus")]
+const EVENT_LABELS: &[&str] = &["mount_point", "server", "export", "mountaddr", "event"];
+
+/// Labels applied to per-mount info metrics.
+#[cfg(feature = "prometheus")]
+const MOUNT_LABELS: &[&str] = &["mount_point", "server", "export", "mountaddr"];
+
+/// Labels applied to the `nfs_mount_info` info metric.
+#[cfg(feature = "prometheus")]
+const INFO_LABELS: &[&str] = &[
+    "mount_point",
+    "server",
+    "export",
+    "mountaddr",
+    "version",
+    "proto",
+    "caps",
+];
+
 /// Prometheus metrics exporter
 #[cfg(feature = "prometheus")]
 pub struct PrometheusExporter {
     registry: Registry,
+    include_labels: bool,
     // NFS Operation metrics
-    nfs_operations_total: Counter,
-    nfs_operation_duration_seconds: Histogram,
-    nfs_operation_bytes_total: Counter,
-    nfs_operation_errors_total: Counter,
-    nfs_operation_timeouts_total: Counter,
+    nfs_operations_total: CounterVec,
+    nfs_operation_duration_seconds: HistogramVec,
+    nfs_operation_bytes_total: CounterVec,
+    nfs_operation_errors_total: CounterVec,
+    nfs_operation_timeouts_total: CounterVec,
+    nfs_requests_total: CounterVec,
+    nfs_transmissions_total: CounterVec,
+    nfs_major_timeouts_total: CounterVec,
 
     // VFS Event metrics
-    nfs_vfs_events_total: Counter,
+    nfs_vfs_events_total: GaugeVec,
 
     // Mount metrics
-    nfs_mount_age_seconds: Gauge,
-    nfs_mount_bytes_read_total: Counter,
-    nfs_mount_bytes_written_total: Counter,
+    nfs_mount_age_seconds: GaugeVec,
+    nfs_mount_bytes_read_total: GaugeVec,
+    nfs_mount_bytes_written_total: GaugeVec,
+    nfs_read_bytes_total: GaugeVec,
+    nfs_write_bytes_total: GaugeVec,
+    nfs_direct_read_bytes_total: GaugeVec,
+    nfs_direct_write_bytes_total: GaugeVec,
+    nfs_total_read_bytes_total: GaugeVec,
+    nfs_total_write_bytes_total: GaugeVec,
+    nfs_read_pages_total: GaugeVec,
+    nfs_write_pages_total: GaugeVec,
+
+    // Info metric (version/protocol/capabilities)
+    nfs_mount_info: GaugeVec,
 }
 
 #[cfg(feature = "prometheus")]
 impl PrometheusExporter {
-    pub fn new() -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+    pub fn new(include_labels: bool) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
         let registry = Registry::new();
 
         // Create metrics
-        let nfs_operations_total = Counter::new(
-            "nfs_operations_total",
-            "Total number of NFS operations performed"
+        let nfs_operations_total = CounterVec::new(
+            prometheus::Opts::new(
+                "nfs_operations_total",
+                "Total number of NFS operations performed",
+            ),
+            OPERATION_LABELS,
         )?;
 
-        let nfs_operation_duration_seconds = Histogram::with_opts(
+        let nfs_operation_duration_seconds = HistogramVec::new(
             prometheus::HistogramOpts::new(
                 "nfs_operation_duration_seconds",
                 "Duration of NFS operations in seconds"
-            ).buckets(vec![0.001, 0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0])
+            ).buckets(vec![0.001, 0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0]),
+            OPERATION_LABELS,
+        )?;
+
+        let nfs_operation_bytes_total = CounterVec::new(
+            prometheus::Opts::new(
+                "nfs_operation_bytes_total",
+                "Total bytes transferred in NFS operations",
+            ),
+            OPERATION_LABELS,
+        )?;
+
+        let nfs_operation_errors_total = CounterVec::new(
+            prometheus::Opts::new(
+                "nfs_operation_errors_total",
+                "Total number of NFS operation errors",
+            ),
+            OPERATION_LABELS,
+        )?;
+
+        let nfs_operation_timeouts_total = CounterVec::new(
+            prometheus::Opts::new(
+                "nfs_operation_timeouts_total",
+                "Total number of NFS operation timeouts",
+            ),
+            OPERATION_LABELS,
+        )?;
+
+        let nfs_requests_total = CounterVec::new(
+            prometheus::Opts::new(
+                "nfs_requests_total",
+                "Total number of NFS requests submitted per operation",
+            ),
+            OPERATION_LABELS,
+        )?;
+
+        let nfs_transmissions_total = CounterVec::new(
+            prometheus::Opts::new(
+                "nfs_transmissions_total",
+                "Total number of NFS RPC transmissions per operation (including retransmits)",
+            ),
+            OPERATION_LABELS,
+        )?;
+
+        let nfs_major_timeouts_total = CounterVec::new(
+            prometheus::Opts::new(
+                "nfs_major_timeouts_total",
+                "Total number of major RPC timeouts per operation",
+            ),
+            OPERATION_LABELS,
+        )?;
+
+        let nfs_vfs_events_total = GaugeVec::new(
+            prometheus::Opts::new(
+                "nfs_vfs_events_total",
+                "Total number of NFS VFS events",
+            ),
+            EVENT_LABELS,
+        )?;
+
+        let nfs_mount_age_seconds = GaugeVec::new(
+            prometheus::Opts::new(
+                "nfs_mount_age_seconds",
+                "Age of NFS mount in seconds",
+            ),
+            MOUNT_LABELS,
+        )?;
+
+        let nfs_mount_bytes_read_total = GaugeVec::new(
+            prometheus::Opts::new(
+                "nfs_mount_bytes_read_total",
+                "Total bytes read from NFS mount",
+            ),
+            MOUNT_LABELS,
+        )?;
+
+        let nfs_mount_bytes_written_total = GaugeVec::new(
+            prometheus::Opts::new(
+                "nfs_mount_bytes_written_total",
+                "Total bytes written to NFS mount",
+            ),
+            MOUNT_LABELS,
         )?;
 
-        let nfs_operation_bytes_total = Counter::new(
-            "nfs_operation_bytes_total",
-            "Total bytes transferred in NFS operations"
+        let nfs_read_bytes_total = GaugeVec::new(
+            prometheus::Opts::new("nfs_read_bytes_total", "Total normal read() bytes"),
+            MOUNT_LABELS,
         )?;
 
-        let nfs_operation_errors_total = Counter::new(
-            "nfs_operation_errors_total",
-            "Total number of NFS operation errors"
+        let nfs_write_bytes_total = GaugeVec::new(
+            prometheus::Opts::new("nfs_write_bytes_total", "Total normal write() bytes"),
+            MOUNT_LABELS,
         )?;
 
-        let nfs_operation_timeouts_total = Counter::new(
-            "nfs_operation_timeouts_total",
-            "Total number of NFS operation timeouts"
+        let nfs_direct_read_bytes_total = GaugeVec::new(
+            prometheus::Opts::new(
+                "nfs_direct_read_bytes_total",
+                "Total O_DIRECT read bytes",
+            ),
+            MOUNT_LABELS,
         )?;
 
-        let nfs_vfs_events_total = Counter::new(
-            "nfs_vfs_events_total",
-            "Total number of NFS VFS events"
+        let nfs_direct_write_bytes_total = GaugeVec::new(
+            prometheus::Opts::new(
+                "nfs_direct_write_bytes_total",
+                "Total O_DIRECT write bytes",
+            ),
+            MOUNT_LABELS,
         )?;
 
-        let nfs_mount_age_seconds = Gauge::new(
-            "nfs_mount_age_seconds",
-            "Age of NFS mount in seconds"
+        let nfs_total_read_bytes_total = GaugeVec::new(
+            prometheus::Opts::new(
+                "nfs_total_read_bytes_total",
+                "Total read bytes sent over the wire to the NFS server",
+            ),
+            MOUNT_LABELS,
         )?;
 
-        let nfs_mount_bytes_read_total = Counter::new(
-            "nfs_mount_bytes_read_total",
-            "Total bytes read from NFS mount"
+        let nfs_total_write_bytes_total = GaugeVec::new(
+            prometheus::Opts::new(
+                "nfs_total_write_bytes_total",
+                "Total write bytes sent over the wire to the NFS server",
+            ),
+            MOUNT_LABELS,
         )?;
 
-        let nfs_mount_bytes_written_total = Counter::new(
-            "nfs_mount_bytes_written_total",
-            "Total bytes written to NFS mount"
+        let nfs_read_pages_total = GaugeVec::new(
+            prometheus::Opts::new("nfs_read_pages_total", "Total pages read from the NFS mount"),
+            MOUNT_LABELS,
+        )?;
+
+        let nfs_write_pages_total = GaugeVec::new(
+            prometheus::Opts::new(
+                "nfs_write_pages_total",
+                "Total pages written to the NFS mount",
+            ),
+            MOUNT_LABELS,
+        )?;
+
+        let nfs_mount_info = GaugeVec::new(
+            prometheus::Opts::new(
+                "nfs_mount_info",
+                "Always 1; carries negotiated NFS version, transport protocol, and \
+                 server capabilities as labels for joining against operation counters",
+            ),
+            INFO_LABELS,
         )?;
 
         // Register metrics
@@ -118,72 +321,360 @@ impl PrometheusExporter {
         registry.register(Box::new(nfs_operation_bytes_total.clone()))?;
         registry.register(Box::new(nfs_operation_errors_total.clone()))?;
         registry.register(Box::new(nfs_operation_timeouts_total.clone()))?;
+        registry.register(Box::new(nfs_requests_total.clone()))?;
+        registry.register(Box::new(nfs_transmissions_total.clone()))?;
+        registry.register(Box::new(nfs_major_timeouts_total.clone()))?;
         registry.register(Box::new(nfs_vfs_events_total.clone()))?;
         registry.register(Box::new(nfs_mount_age_seconds.clone()))?;
         registry.register(Box::new(nfs_mount_bytes_read_total.clone()))?;
         registry.register(Box::new(nfs_mount_bytes_written_total.clone()))?;
+        registry.register(Box::new(nfs_read_bytes_total.clone()))?;
+        registry.register(Box::new(nfs_write_bytes_total.clone()))?;
+        registry.register(Box::new(nfs_direct_read_bytes_total.clone()))?;
+        registry.register(Box::new(nfs_direct_write_bytes_total.clone()))?;
+        registry.register(Box::new(nfs_total_read_bytes_total.clone()))?;
+        registry.register(Box::new(nfs_total_write_bytes_total.clone()))?;
+        registry.register(Box::new(nfs_read_pages_total.clone()))?;
+        registry.register(Box::new(nfs_write_pages_total.clone()))?;
+        registry.register(Box::new(nfs_mount_info.clone()))?;
 
         Ok(Self {
             registry,
+            include_labels,
             nfs_operations_total,
             nfs_operation_duration_seconds,
             nfs_operation_bytes_total,
             nfs_operation_errors_total,
             nfs_operation_timeouts_total,
+            nfs_requests_total,
+            nfs_transmissions_total,
+            nfs_major_timeouts_total,
             nfs_vfs_events_total,
             nfs_mount_age_seconds,
             nfs_mount_bytes_read_total,
             nfs_mount_bytes_written_total,
+            nfs_read_bytes_total,
+            nfs_write_bytes_total,
+            nfs_direct_read_bytes_total,
+            nfs_direct_write_bytes_total,
+            nfs_total_read_bytes_total,
+            nfs_total_write_bytes_total,
+            nfs_read_pages_total,
+            nfs_write_pages_total,
+            nfs_mount_info,
+        })
+    }
+
+    /// Label values for a per-operation metric. When `include_labels` is
+    /// disabled every mount/operation collapses onto the same empty-label
+    /// series, matching the old unlabeled behavior.
+    fn operation_label_values<'a>(&self, mount: &'a NFSMount, operation: &'a str) -> [&'a str; 5] {
+        if self.include_labels {
+            [
+                &mount.mount_point,
+                &mount.server,
+                &mount.export,
+                mount.mount_addr.as_deref().unwrap_or(""),
+                operation,
+            ]
+        } else {
+            ["", "", "", "", ""]
+        }
+    }
+
+    /// Label values for a VFS event metric.
+    fn event_label_values<'a>(&self, mount: &'a NFSMount, event: &'a str) -> [&'a str; 5] {
+        if self.include_labels {
+            [
+                &mount.mount_point,
+                &mount.server,
+                &mount.export,
+                mount.mount_addr.as_deref().unwrap_or(""),
+                event,
+            ]
+        } else {
+            ["", "", "", "", ""]
+        }
+    }
+
+    /// Label values for a per-mount info metric.
+    fn mount_label_values<'a>(&self, mount: &'a NFSMount) -> [&'a str; 4] {
+        if self.include_labels {
+            [
+                &mount.mount_point,
+                &mount.server,
+                &mount.export,
+                mount.mount_addr.as_deref().unwrap_or(""),
+            ]
+        } else {
+            ["", "", "", ""]
+        }
+    }
+
+    /// Label values for the `nfs_mount_info` info metric, including the
+    /// decoded `NFS_CAP_*` names so dashboards can join capability state
+    /// against the operation counters.
+    fn info_label_values<'a>(&self, mount: &'a NFSMount, caps: &'a str) -> [&'a str; 7] {
+        if self.include_labels {
+            [
+                &mount.mount_point,
+                &mount.server,
+                &mount.export,
+                mount.mount_addr.as_deref().unwrap_or(""),
+                mount.nfs_version.as_deref().unwrap_or(""),
+                mount.proto.as_deref().unwrap_or(""),
+                caps,
+            ]
+        } else {
+            ["", "", "", "", "", "", ""]
+        }
+    }
+
+    /// Start the blocking scrape endpoint on its own thread and return a handle
+    /// that can be used to shut it down and join the thread.
+    pub fn start_server(
+        &self,
+        port: u16,
+    ) -> Result<PrometheusServerHandle, Box<dyn std::error::Error + Send + Sync>> {
+        let registry = self.registry.clone();
+        let listener = TcpListener::bind(("0.0.0.0", port))?;
+        listener.set_nonblocking(true)?;
+
+        let shutdown = Arc::new(AtomicBool::new(false));
+        let thread_shutdown = shutdown.clone();
+
+        let join_handle = thread::spawn(move || {
+            while !thread_shutdown.load(Ordering::SeqCst) {
+                match listener.accept() {
+                    Ok((stream, _addr)) => {
+                        if let Err(e) = handle_scrape_connection(stream, &registry) {
+                            eprintln!("Prometheus scrape connection error: {}", e);
+                        }
+                    }
+                    Err(e) if e.kind() == std::io::ErrorKind::WouldBlock => {
+                        thread::sleep(std::time::Duration::from_millis(100));
+                    }
+                    Err(e) => {
+                        eprintln!("Prometheus listener error: {}", e);
+                        break;
+                    }
+                }
+            }
+        });
+
+        Ok(PrometheusServerHandle {
+            shutdown,
+            join_handle: Some(join_handle),
         })
     }
+}
+
+/// Handle for the embedded Prometheus scrape server, returned by `start_server`.
+#[cfg(feature = "prometheus")]
+pub struct PrometheusServerHandle {
+    shutdown: Arc<AtomicBool>,
+    join_handle: Option<JoinHandle<()>>,
+}
+
+#[cfg(feature = "prometheus")]
+impl PrometheusServerHandle {
+    /// Signal the server thread to stop and wait for it to exit.
+    pub fn shutdown(mut self) {
+        self.shutdown.store(true, Ordering::SeqCst);
+        if let Some(handle) = self.join_handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
 
-    pub fn start_server(&self, port: u16) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
-        // This would start an HTTP server for Prometheus to scrape
-        // Implementation would use hyper + tower to serve /metrics endpoint
-        todo!("Implement HTTP server for metrics endpoint")
+#[cfg(feature = "prometheus")]
+impl Drop for PrometheusServerHandle {
+    fn drop(&mut self) {
+        self.shutdown.store(true, Ordering::SeqCst);
+        if let Some(handle) = self.join_handle.take() {
+            let _ = handle.join();
+        }
     }
 }
 
+/// Serve a single request: `GET /metrics` returns the text-format registry
+/// output, `GET /` returns a small landing page, everything else is a 404.
+#[cfg(feature = "prometheus")]
+fn handle_scrape_connection(
+    mut stream: TcpStream,
+    registry: &Registry,
+) -> std::io::Result<()> {
+    stream.set_nonblocking(false)?;
+
+    let mut reader = BufReader::new(stream.try_clone()?);
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line)?;
+
+    let path = request_line
+        .split_whitespace()
+        .nth(1)
+        .unwrap_or("/")
+        .to_string();
+
+    // Drain the remaining request headers so the client doesn't see a reset
+    // connection before we've finished reading what it sent.
+    loop {
+        let mut header_line = String::new();
+        if reader.read_line(&mut header_line)? == 0 || header_line == "\r\n" {
+            break;
+        }
+    }
+
+    match path.as_str() {
+        "/metrics" => {
+            let encoder = TextEncoder::new();
+            let metric_families = registry.gather();
+            let mut body = Vec::new();
+            encoder
+                .encode(&metric_families, &mut body)
+                .map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e))?;
+            write_response(
+                &mut stream,
+                "200 OK",
+                "text/plain; version=0.0.4",
+                &body,
+            )
+        }
+        "/" => write_response(
+            &mut stream,
+            "200 OK",
+            "text/plain; charset=utf-8",
+            b"nfs-gaze metrics exporter\nScrape metrics at /metrics\n",
+        ),
+        _ => write_response(&mut stream, "404 Not Found", "text/plain; charset=utf-8", b"not found\n"),
+    }
+}
+
+#[cfg(feature = "prometheus")]
+fn write_response(
+    stream: &mut TcpStream,
+    status: &str,
+    content_type: &str,
+    body: &[u8],
+) -> std::io::Result<()> {
+    write!(
+        stream,
+        "HTTP/1.1 {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+        status,
+        content_type,
+        body.len()
+    )?;
+    stream.write_all(body)?;
+    stream.flush()
+}
+
 #[cfg(feature = "prometheus")]
 impl MetricsExporter for PrometheusExporter {
     fn export_nfs_operation_metrics(&self, mount: &NFSMount, stats: &[DeltaStats]) {
         for stat in stats {
+            let labels = self.operation_label_values(mount, &stat.operation);
+
             // Add operation count
-            self.nfs_operations_total.inc_by(stat.delta_ops as f64);
+            self.nfs_operations_total
+                .with_label_values(&labels)
+                .inc_by(stat.delta_ops as f64);
 
             // Add duration histogram (convert ms to seconds)
             if stat.avg_rtt > 0.0 {
-                self.nfs_operation_duration_seconds.observe(stat.avg_rtt / 1000.0);
+                self.nfs_operation_duration_seconds
+                    .with_label_values(&labels)
+                    .observe(stat.avg_rtt / 1000.0);
             }
 
             // Add bytes transferred
-            self.nfs_operation_bytes_total.inc_by(stat.delta_bytes as f64);
+            self.nfs_operation_bytes_total
+                .with_label_values(&labels)
+                .inc_by(stat.delta_bytes as f64);
 
             // Add errors
             if stat.delta_errors > 0 {
-                self.nfs_operation_errors_total.inc_by(stat.delta_errors as f64);
+                self.nfs_operation_errors_total
+                    .with_label_values(&labels)
+                    .inc_by(stat.delta_errors as f64);
             }
 
             // Add timeouts
             if stat.delta_retrans > 0 {
-                self.nfs_operation_timeouts_total.inc_by(stat.delta_retrans as f64);
+                self.nfs_operation_timeouts_total
+                    .with_label_values(&labels)
+                    .inc_by(stat.delta_retrans as f64);
+            }
+
+            // Requests, transmissions (including retransmits), and major timeouts
+            self.nfs_requests_total
+                .with_label_values(&labels)
+                .inc_by(stat.delta_ops as f64);
+            self.nfs_transmissions_total
+                .with_label_values(&labels)
+                .inc_by(stat.delta_ntrans as f64);
+            if stat.delta_retrans > 0 {
+                self.nfs_major_timeouts_total
+                    .with_label_values(&labels)
+                    .inc_by(stat.delta_retrans as f64);
             }
         }
     }
 
     fn export_nfs_events_metrics(&self, mount: &NFSMount, events: &NFSEvents) {
-        // Export VFS events as incremental counters
-        self.nfs_vfs_events_total.inc_by(events.vfs_open as f64);
-        self.nfs_vfs_events_total.inc_by(events.vfs_lookup as f64);
-        self.nfs_vfs_events_total.inc_by(events.vfs_read_page as f64);
-        self.nfs_vfs_events_total.inc_by(events.vfs_write_page as f64);
-        // ... add other VFS events as needed
+        // Export each VFS event type as its own labeled series instead of
+        // collapsing them all into one counter. These are cumulative
+        // since-mount-time values straight from mountstats, so they're
+        // recorded as gauges rather than fed into a Counter's `inc_by`,
+        // which would double-count them on every export tick.
+        for (event, count) in vfs_event_pairs(events) {
+            let labels = self.event_label_values(mount, event);
+            self.nfs_vfs_events_total
+                .with_label_values(&labels)
+                .set(count as f64);
+        }
     }
 
     fn export_mount_info_metrics(&self, mount: &NFSMount) {
-        self.nfs_mount_age_seconds.set(mount.age as f64);
-        self.nfs_mount_bytes_read_total.inc_by(mount.bytes_read as f64);
-        self.nfs_mount_bytes_written_total.inc_by(mount.bytes_write as f64);
+        let labels = self.mount_label_values(mount);
+        self.nfs_mount_age_seconds
+            .with_label_values(&labels)
+            .set(mount.age as f64);
+        self.nfs_mount_bytes_read_total
+            .with_label_values(&labels)
+            .set(mount.bytes_read as f64);
+        self.nfs_mount_bytes_written_total
+            .with_label_values(&labels)
+            .set(mount.bytes_write as f64);
+
+        self.nfs_read_bytes_total
+            .with_label_values(&labels)
+            .set(mount.bytes_read as f64);
+        self.nfs_write_bytes_total
+            .with_label_values(&labels)
+            .set(mount.bytes_write as f64);
+        self.nfs_direct_read_bytes_total
+            .with_label_values(&labels)
+            .set(mount.direct_bytes_read as f64);
+        self.nfs_direct_write_bytes_total
+            .with_label_values(&labels)
+            .set(mount.direct_bytes_write as f64);
+        self.nfs_total_read_bytes_total
+            .with_label_values(&labels)
+            .set(mount.server_bytes_read as f64);
+        self.nfs_total_write_bytes_total
+            .with_label_values(&labels)
+            .set(mount.server_bytes_write as f64);
+        self.nfs_read_pages_total
+            .with_label_values(&labels)
+            .set(mount.read_pages as f64);
+        self.nfs_write_pages_total
+            .with_label_values(&labels)
+            .set(mount.write_pages as f64);
+
+        // Info metric: always 1, carries version/proto/caps as labels.
+        let caps = mount.server_caps.names();
+        let info_labels = self.info_label_values(mount, &caps);
+        self.nfs_mount_info.with_label_values(&info_labels).set(1.0);
     }
 
     fn get_metrics_output(&self) -> Option<String> {
@@ -199,22 +690,63 @@ impl MetricsExporter for PrometheusExporter {
     }
 }
 
-/// OpenTelemetry metrics exporter
+/// Latest known state for one mount, polled by the observable gauge
+/// callbacks below at whatever cadence the `PeriodicReader` collects on.
+#[cfg(feature = "opentelemetry")]
+#[derive(Debug, Clone, Default)]
+struct MountInfoSnapshot {
+    mount_point: String,
+    server: String,
+    age: f64,
+    bytes_read: f64,
+    bytes_write: f64,
+}
+
+/// OpenTelemetry metrics exporter. Pushes via OTLP on a `PeriodicReader`
+/// that flushes every `MetricsConfig::export_interval`, following the
+/// metrics-SDK split of a `MetricReader` (the `PeriodicReader`) driving a
+/// `PushMetricExporter` (the OTLP exporter) independently of collection.
 #[cfg(feature = "opentelemetry")]
 pub struct OpenTelemetryExporter {
-    meter: Meter,
+    // Kept alive so the periodic reader keeps flushing for the exporter's
+    // lifetime. A clone is also installed as the global meter provider, so
+    // dropping the exporter alone does not shut the reader down.
+    provider: SdkMeterProvider,
     // Instruments
     operations_counter: Counter<u64>,
     duration_histogram: Histogram<f64>,
     bytes_counter: Counter<u64>,
     errors_counter: Counter<u64>,
     events_counter: Counter<u64>,
+    // Mount info gauges are observable: the SDK calls back into them at
+    // collection time rather than being pushed to synchronously, so the
+    // latest values are staged here by `export_mount_info_metrics`.
+    mount_snapshots: Arc<Mutex<Vec<MountInfoSnapshot>>>,
+    _mount_age_gauge: ObservableGauge<f64>,
+    _mount_bytes_read_gauge: ObservableGauge<f64>,
+    _mount_bytes_write_gauge: ObservableGauge<f64>,
 }
 
 #[cfg(feature = "opentelemetry")]
 impl OpenTelemetryExporter {
-    pub fn new() -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
-        let meter = opentelemetry::global::meter("nfs-gaze");
+    pub fn new(
+        otel_endpoint: Option<&str>,
+        export_interval: Duration,
+    ) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        let mut exporter_builder = MetricExporter::builder().with_tonic();
+        if let Some(endpoint) = otel_endpoint {
+            exporter_builder = exporter_builder.with_endpoint(endpoint);
+        }
+        let exporter = exporter_builder.build()?;
+
+        let reader = PeriodicReader::builder(exporter)
+            .with_interval(export_interval)
+            .build();
+
+        let provider = SdkMeterProvider::builder().with_reader(reader).build();
+        global::set_meter_provider(provider.clone());
+
+        let meter = provider.meter("nfs-gaze");
 
         let operations_counter = meter
             .u64_counter("nfs_operations_total")
@@ -241,13 +773,70 @@ impl OpenTelemetryExporter {
             .with_description("Total number of NFS VFS events")
             .init();
 
+        let mount_snapshots: Arc<Mutex<Vec<MountInfoSnapshot>>> = Arc::new(Mutex::new(Vec::new()));
+
+        let age_snapshots = mount_snapshots.clone();
+        let mount_age_gauge = meter
+            .f64_observable_gauge("nfs_mount_age_seconds")
+            .with_description("Age of NFS mount in seconds")
+            .with_callback(move |observer| {
+                for snapshot in age_snapshots.lock().unwrap().iter() {
+                    observer.observe(
+                        snapshot.age,
+                        &[
+                            KeyValue::new("mount_point", snapshot.mount_point.clone()),
+                            KeyValue::new("server", snapshot.server.clone()),
+                        ],
+                    );
+                }
+            })
+            .init();
+
+        let read_snapshots = mount_snapshots.clone();
+        let mount_bytes_read_gauge = meter
+            .f64_observable_gauge("nfs_mount_bytes_read_total")
+            .with_description("Total bytes read from NFS mount")
+            .with_callback(move |observer| {
+                for snapshot in read_snapshots.lock().unwrap().iter() {
+                    observer.observe(
+                        snapshot.bytes_read,
+                        &[
+                            KeyValue::new("mount_point", snapshot.mount_point.clone()),
+                            KeyValue::new("server", snapshot.server.clone()),
+                        ],
+                    );
+                }
+            })
+            .init();
+
+        let write_snapshots = mount_snapshots.clone();
+        let mount_bytes_write_gauge = meter
+            .f64_observable_gauge("nfs_mount_bytes_written_total")
+            .with_description("Total bytes written to NFS mount")
+            .with_callback(move |observer| {
+                for snapshot in write_snapshots.lock().unwrap().iter() {
+                    observer.observe(
+                        snapshot.bytes_write,
+                        &[
+                            KeyValue::new("mount_point", snapshot.mount_point.clone()),
+                            KeyValue::new("server", snapshot.server.clone()),
+                        ],
+                    );
+                }
+            })
+            .init();
+
         Ok(Self {
-            meter,
+            provider,
             operations_counter,
             duration_histogram,
             bytes_counter,
             errors_counter,
             events_counter,
+            mount_snapshots,
+            _mount_age_gauge: mount_age_gauge,
+            _mount_bytes_read_gauge: mount_bytes_read_gauge,
+            _mount_bytes_write_gauge: mount_bytes_write_gauge,
         })
     }
 }
@@ -255,8 +844,6 @@ impl OpenTelemetryExporter {
 #[cfg(feature = "opentelemetry")]
 impl MetricsExporter for OpenTelemetryExporter {
     fn export_nfs_operation_metrics(&self, mount: &NFSMount, stats: &[DeltaStats]) {
-        let ctx = Context::current();
-
         for stat in stats {
             let labels = [
                 KeyValue::new("mount_point", mount.mount_point.clone()),
@@ -265,45 +852,160 @@ impl MetricsExporter for OpenTelemetryExporter {
             ];
 
             // Record operations
-            self.operations_counter.add(&ctx, stat.delta_ops as u64, &labels);
+            self.operations_counter.add(stat.delta_ops as u64, &labels);
 
             // Record duration
             if stat.avg_rtt > 0.0 {
-                self.duration_histogram.record(&ctx, stat.avg_rtt / 1000.0, &labels);
+                self.duration_histogram.record(stat.avg_rtt / 1000.0, &labels);
             }
 
             // Record bytes
-            self.bytes_counter.add(&ctx, stat.delta_bytes as u64, &labels);
+            self.bytes_counter.add(stat.delta_bytes as u64, &labels);
 
             // Record errors
             if stat.delta_errors > 0 {
-                self.errors_counter.add(&ctx, stat.delta_errors as u64, &labels);
+                self.errors_counter.add(stat.delta_errors as u64, &labels);
             }
         }
     }
 
     fn export_nfs_events_metrics(&self, mount: &NFSMount, events: &NFSEvents) {
-        let ctx = Context::current();
         let labels = [
             KeyValue::new("mount_point", mount.mount_point.clone()),
             KeyValue::new("server", mount.server.clone()),
         ];
 
         // Export key VFS events
-        self.events_counter.add(&ctx, events.vfs_open as u64, &labels);
-        self.events_counter.add(&ctx, events.vfs_lookup as u64, &labels);
-        self.events_counter.add(&ctx, events.vfs_read_page as u64, &labels);
-        self.events_counter.add(&ctx, events.vfs_write_page as u64, &labels);
+        self.events_counter.add(events.vfs_open as u64, &labels);
+        self.events_counter.add(events.vfs_lookup as u64, &labels);
+        self.events_counter.add(events.vfs_read_page as u64, &labels);
+        self.events_counter.add(events.vfs_write_page as u64, &labels);
     }
 
     fn export_mount_info_metrics(&self, mount: &NFSMount) {
-        // Mount info metrics would be gauges - implementation depends on OTEL version
-        // For now, we'll skip these as they require gauge instruments
+        let mut snapshots = self.mount_snapshots.lock().unwrap();
+        let snapshot = MountInfoSnapshot {
+            mount_point: mount.mount_point.clone(),
+            server: mount.server.clone(),
+            age: mount.age as f64,
+            bytes_read: mount.bytes_read as f64,
+            bytes_write: mount.bytes_write as f64,
+        };
+
+        match snapshots.iter_mut().find(|s| s.mount_point == mount.mount_point) {
+            Some(existing) => *existing = snapshot,
+            None => snapshots.push(snapshot),
+        }
     }
 
     fn get_metrics_output(&self) -> Option<String> {
-        // OpenTelemetry doesn't provide text output like Prometheus
-        // Metrics are pushed to collectors
+        // OpenTelemetry doesn't provide text output like Prometheus;
+        // metrics are pushed to the OTLP collector by the periodic reader.
+        None
+    }
+}
+
+/// Graphite/StatsD plaintext exporter. Formats every metric as a Graphite
+/// line (`path value timestamp\n`) and ships it over a buffered TCP socket
+/// to a configurable `host:port`, for shops that aggregate through
+/// Graphite/carbon rather than Prometheus or OTLP.
+#[cfg(feature = "graphite")]
+pub struct GraphiteExporter {
+    stream: Mutex<std::io::BufWriter<std::net::TcpStream>>,
+    prefix: String,
+}
+
+#[cfg(feature = "graphite")]
+impl GraphiteExporter {
+    pub fn new(
+        endpoint: &str,
+        metric_prefix: &str,
+    ) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        let stream = std::net::TcpStream::connect(endpoint)?;
+        Ok(Self {
+            stream: Mutex::new(std::io::BufWriter::new(stream)),
+            prefix: metric_prefix.to_string(),
+        })
+    }
+
+    /// Graphite paths are dot-separated, so anything that could introduce a
+    /// spurious path segment (dots, slashes, whitespace) is flattened to `_`.
+    fn sanitize(component: &str) -> String {
+        component
+            .chars()
+            .map(|c| if c == '.' || c == '/' || c.is_whitespace() { '_' } else { c })
+            .collect()
+    }
+
+    fn unix_timestamp() -> u64 {
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0)
+    }
+
+    /// Write one `prefix.server.mount.operation.metric value timestamp`
+    /// line, logging (rather than failing the whole export) on a broken
+    /// connection since metrics export should never take down monitoring.
+    fn send_line(&self, path_suffix: &str, value: f64) {
+        let line = format!(
+            "{}.{} {} {}\n",
+            self.prefix,
+            path_suffix,
+            value,
+            Self::unix_timestamp()
+        );
+
+        let mut stream = self.stream.lock().unwrap();
+        if let Err(e) = stream.write_all(line.as_bytes()).and_then(|_| stream.flush()) {
+            eprintln!("Graphite export error: {}", e);
+        }
+    }
+
+    fn mount_path(mount: &NFSMount) -> String {
+        format!(
+            "{}.{}",
+            Self::sanitize(&mount.server),
+            Self::sanitize(&mount.mount_point)
+        )
+    }
+}
+
+#[cfg(feature = "graphite")]
+impl MetricsExporter for GraphiteExporter {
+    fn export_nfs_operation_metrics(&self, mount: &NFSMount, stats: &[DeltaStats]) {
+        let mount_path = Self::mount_path(mount);
+
+        for stat in stats {
+            let op_path = format!("{}.{}", mount_path, Self::sanitize(&stat.operation));
+            self.send_line(&format!("{}.ops", op_path), stat.delta_ops as f64);
+            self.send_line(&format!("{}.bytes", op_path), stat.delta_bytes as f64);
+            self.send_line(&format!("{}.errors", op_path), stat.delta_errors as f64);
+            self.send_line(&format!("{}.retransmits", op_path), stat.delta_retrans as f64);
+            self.send_line(&format!("{}.avg_rtt_ms", op_path), stat.avg_rtt);
+            self.send_line(&format!("{}.avg_exec_ms", op_path), stat.avg_exec);
+            self.send_line(&format!("{}.iops", op_path), stat.iops);
+        }
+    }
+
+    fn export_nfs_events_metrics(&self, mount: &NFSMount, events: &NFSEvents) {
+        let mount_path = Self::mount_path(mount);
+
+        for (event, count) in vfs_event_pairs(events) {
+            self.send_line(&format!("{}.events.{}", mount_path, event), count as f64);
+        }
+    }
+
+    fn export_mount_info_metrics(&self, mount: &NFSMount) {
+        let mount_path = Self::mount_path(mount);
+        self.send_line(&format!("{}.age_seconds", mount_path), mount.age as f64);
+        self.send_line(&format!("{}.bytes_read", mount_path), mount.bytes_read as f64);
+        self.send_line(&format!("{}.bytes_write", mount_path), mount.bytes_write as f64);
+    }
+
+    fn get_metrics_output(&self) -> Option<String> {
+        // Graphite metrics are pushed line-by-line over the socket as
+        // they're recorded; there's no pull-style text dump to return.
         None
     }
 }
@@ -312,35 +1014,60 @@ impl MetricsExporter for OpenTelemetryExporter {
 pub struct MetricsManager {
     exporters: Vec<Box<dyn MetricsExporter>>,
     config: MetricsConfig,
+    #[cfg(feature = "prometheus")]
+    prometheus_server: Option<PrometheusServerHandle>,
 }
 
 impl MetricsManager {
     pub fn new(config: MetricsConfig) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
-        #[cfg(any(feature = "prometheus", feature = "opentelemetry"))]
+        #[cfg(any(feature = "prometheus", feature = "opentelemetry", feature = "graphite"))]
         {
             let mut exporters: Vec<Box<dyn MetricsExporter>> = Vec::new();
 
+            #[cfg(feature = "prometheus")]
+            let mut prometheus_server = None;
+
             #[cfg(feature = "prometheus")]
             if config.enable_prometheus {
-                exporters.push(Box::new(PrometheusExporter::new()?));
+                let exporter = PrometheusExporter::new(config.include_labels)?;
+                prometheus_server = Some(exporter.start_server(config.prometheus_port)?);
+                exporters.push(Box::new(exporter));
             }
 
             #[cfg(feature = "opentelemetry")]
             if config.enable_opentelemetry {
-                exporters.push(Box::new(OpenTelemetryExporter::new()?));
+                exporters.push(Box::new(OpenTelemetryExporter::new(
+                    config.otel_endpoint.as_deref(),
+                    config.export_interval,
+                )?));
             }
 
-            Ok(Self { exporters, config })
+            #[cfg(feature = "graphite")]
+            if config.enable_graphite {
+                if let Some(ref endpoint) = config.graphite_endpoint {
+                    exporters.push(Box::new(GraphiteExporter::new(
+                        endpoint,
+                        &config.metric_prefix,
+                    )?));
+                }
+            }
+
+            Ok(Self {
+                exporters,
+                config,
+                #[cfg(feature = "prometheus")]
+                prometheus_server,
+            })
         }
 
-        #[cfg(not(any(feature = "prometheus", feature = "opentelemetry")))]
+        #[cfg(not(any(feature = "prometheus", feature = "opentelemetry", feature = "graphite")))]
         {
             Ok(Self { exporters: Vec::new(), config })
         }
     }
 
     pub fn export_metrics(&self, mount: &NFSMount, stats: &[DeltaStats]) {
-        #[cfg(any(feature = "prometheus", feature = "opentelemetry"))]
+        #[cfg(any(feature = "prometheus", feature = "opentelemetry", feature = "graphite"))]
         {
             for exporter in &self.exporters {
                 exporter.export_nfs_operation_metrics(mount, stats);
@@ -353,7 +1080,7 @@ impl MetricsManager {
             }
         }
 
-        #[cfg(not(any(feature = "prometheus", feature = "opentelemetry")))]
+        #[cfg(not(any(feature = "prometheus", feature = "opentelemetry", feature = "graphite")))]
         {
             // No-op when observability features are disabled
             let _ = (mount, stats);
@@ -373,12 +1100,14 @@ impl MetricsManager {
     }
 
     pub fn is_enabled(&self) -> bool {
-        #[cfg(any(feature = "prometheus", feature = "opentelemetry"))]
+        #[cfg(any(feature = "prometheus", feature = "opentelemetry", feature = "graphite"))]
         {
-            self.config.enable_prometheus || self.config.enable_opentelemetry
+            self.config.enable_prometheus
+                || self.config.enable_opentelemetry
+                || self.config.enable_graphite
         }
 
-        #[cfg(not(any(feature = "prometheus", feature = "opentelemetry")))]
+        #[cfg(not(any(feature = "prometheus", feature = "opentelemetry", feature = "graphite")))]
         {
             false
         }
@@ -388,7 +1117,7 @@ impl MetricsManager {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::types::{NFSOperation, NFSEvents};
+    use crate::types::{NFSOperation, NFSEvents, NFSServerCaps};
     use std::collections::HashMap;
 
     fn create_test_mount() -> NFSMount {
@@ -416,6 +1145,24 @@ mod tests {
             events: Some(NFSEvents::default()),
             bytes_read: 1048576,
             bytes_write: 2097152,
+            direct_bytes_read: 0,
+            direct_bytes_write: 0,
+            server_bytes_read: 1048576,
+            server_bytes_write: 2097152,
+            read_pages: 256,
+            write_pages: 512,
+            mount_addr: None,
+            server_caps: NFSServerCaps::default(),
+            nfs_version: None,
+            proto: None,
+            xprt_proto: None,
+            xprt_sends: 0,
+            xprt_bklog_u: 0,
+            xprt_retrans: 0,
+            transport: None,
+            options: None,
+            fstype: None,
+            statvers: crate::types::MountstatsVersion::Unknown,
         }
     }
 
@@ -431,12 +1178,14 @@ mod tests {
             delta_queue: 50,
             delta_errors: 1,
             delta_retrans: 2,
+            delta_ntrans: 10,
             avg_rtt: 10.0,
             avg_exec: 20.0,
             avg_queue: 5.0,
             kb_per_op: 1.0,
             kb_per_sec: 10.0,
             iops: 10.0,
+            reset_detected: false,
         }]
     }
 
@@ -470,14 +1219,35 @@ mod tests {
     #[cfg(feature = "prometheus")]
     #[test]
     fn test_prometheus_exporter_creation() {
-        let exporter = PrometheusExporter::new();
+        let exporter = PrometheusExporter::new(true);
         assert!(exporter.is_ok());
     }
 
     #[cfg(feature = "opentelemetry")]
     #[test]
     fn test_opentelemetry_exporter_creation() {
-        let exporter = OpenTelemetryExporter::new();
+        let exporter = OpenTelemetryExporter::new(None, Duration::from_secs(10));
         assert!(exporter.is_ok());
     }
+
+    #[cfg(feature = "graphite")]
+    #[test]
+    fn test_graphite_exporter_sends_lines() {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").expect("bind listener");
+        let addr = listener.local_addr().unwrap();
+
+        let handle = std::thread::spawn(move || {
+            let (stream, _) = listener.accept().expect("accept connection");
+            let mut reader = std::io::BufReader::new(stream);
+            let mut line = String::new();
+            std::io::BufRead::read_line(&mut reader, &mut line).expect("read line");
+            line
+        });
+
+        let exporter = GraphiteExporter::new(&addr.to_string(), "nfs_gaze").expect("connect");
+        exporter.export_mount_info_metrics(&create_test_mount());
+
+        let line = handle.join().expect("reader thread");
+        assert!(line.starts_with("nfs_gaze.server._mnt_nfs.age_seconds "));
+    }
 }
\ No newline at end of file
@@ -1,6 +1,15 @@
-use crate::display::display_stats_simple;
+use crate::cli::OutputFormat;
+use crate::display::{
+    display_attr_stats, display_stats_csv, display_stats_iostat, display_stats_json,
+    display_stats_ndjson, display_stats_simple,
+};
+#[cfg(target_os = "linux")]
+use crate::network::{compute_network_context, read_network_snapshot, NetworkSnapshot};
 use crate::parser::parse_mountstats;
-use crate::stats::{calculate_delta_stats, filter_operations};
+use crate::stats::{
+    calculate_delta_stats, calculate_event_delta_stats, calculate_transport_stats,
+    filter_operations, OperationSelector,
+};
 use crate::types::{NFSMount, Result};
 use chrono::Utc;
 use signal_hook::{consts::SIGINT, consts::SIGTERM, iterator::Signals};
@@ -11,6 +20,49 @@ use std::sync::Arc;
 use std::thread;
 use std::time::{Duration, Instant};
 
+/// Tick used to poll per-class sampling deadlines. Kept well below the
+/// smallest supported `--interval`/`--attr-interval`/`--caps-interval` so
+/// that no class's deadline is missed by more than this much.
+const SLEEP_INTERVAL: Duration = Duration::from_millis(500);
+
+/// A category of mountstats data sampled on its own cadence.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum MetricClass {
+    /// Per-operation I/O deltas (the `-i`/`--interval` clock).
+    Io,
+    /// Attribute-cache efficiency counters (`--attr`/`--attr-interval`).
+    Attr,
+    /// Negotiated server capability re-checks (`--caps`/`--caps-interval`).
+    Caps,
+}
+
+/// Tracks when a `MetricClass` was last sampled and how often it's due.
+struct SampleSchedule {
+    last_sampled: Instant,
+    period: Duration,
+}
+
+impl SampleSchedule {
+    fn new(period: Duration) -> Self {
+        Self {
+            last_sampled: Instant::now(),
+            period,
+        }
+    }
+
+    fn is_due(&self, now: Instant) -> bool {
+        now.duration_since(self.last_sampled) >= self.period
+    }
+
+    fn elapsed_seconds(&self, now: Instant) -> f64 {
+        now.duration_since(self.last_sampled).as_secs_f64()
+    }
+
+    fn mark_sampled(&mut self, now: Instant) {
+        self.last_sampled = now;
+    }
+}
+
 /// Main monitoring structure
 pub struct Monitor {
     pub running: Arc<AtomicBool>,
@@ -58,6 +110,7 @@ impl Monitor {
         mount_point: &Option<String>,
         mounts: &[NFSMount],
         operations_filter: &HashSet<String>,
+        show_caps: bool,
     ) -> io::Result<()> {
         writeln!(writer, "NFS I/O Statistics Monitor")?;
         writeln!(writer, "==========================")?;
@@ -76,11 +129,27 @@ impl Monitor {
             writeln!(writer, "Filtering operations: {:?}", operations_filter)?;
         }
 
+        if show_caps {
+            writeln!(writer)?;
+            writeln!(writer, "Server capabilities:")?;
+            for mount in mounts {
+                let names = mount.server_caps.names();
+                if names.is_empty() {
+                    writeln!(writer, "  {}: (none reported)", mount.mount_point)?;
+                } else {
+                    writeln!(writer, "  {}: {}", mount.mount_point, names)?;
+                }
+            }
+        }
+
         writeln!(writer)?;
         Ok(())
     }
 
-    /// Main monitoring loop
+    /// Main monitoring loop. Fast I/O deltas, attribute-cache counters, and
+    /// capability re-checks are sampled on independent cadences (see
+    /// `MetricClass`) rather than all riding the same `interval` clock.
+    #[allow(clippy::too_many_arguments)]
     pub fn monitoring_loop<W: Write>(
         &self,
         writer: &mut W,
@@ -88,17 +157,37 @@ impl Monitor {
         monitor_mounts: Vec<NFSMount>,
         operations_filter: HashSet<String>,
         interval: Duration,
+        attr_interval: Duration,
+        caps_interval: Duration,
         count: usize,
         show_bandwidth: bool,
         clear_screen: bool,
+        iostat: bool,
+        show_attr: bool,
+        show_caps: bool,
+        output_format: OutputFormat,
     ) -> Result<()> {
-        let mut previous_mounts: HashMap<String, NFSMount> = monitor_mounts
+        let mut csv_header_written = false;
+        let operations_selector = OperationSelector::Names(operations_filter);
+        let seed: HashMap<String, NFSMount> = monitor_mounts
             .iter()
             .map(|m| (m.mount_point.clone(), m.clone()))
             .collect();
+        let mut io_baseline = seed.clone();
+        let mut attr_baseline = seed;
+        #[cfg(target_os = "linux")]
+        let mut network_baseline: NetworkSnapshot = read_network_snapshot();
+
+        let mut schedule: HashMap<MetricClass, SampleSchedule> = HashMap::new();
+        schedule.insert(MetricClass::Io, SampleSchedule::new(interval));
+        if show_attr {
+            schedule.insert(MetricClass::Attr, SampleSchedule::new(attr_interval));
+        }
+        if show_caps {
+            schedule.insert(MetricClass::Caps, SampleSchedule::new(caps_interval));
+        }
 
         let mut iteration = 0;
-        let mut last_update = Instant::now();
 
         while self.running.load(Ordering::SeqCst) {
             // Check if we've reached the iteration limit
@@ -106,8 +195,18 @@ impl Monitor {
                 break;
             }
 
-            // Sleep for the specified interval
-            thread::sleep(interval);
+            // Tick at a fine grain; only classes past their deadline do work
+            thread::sleep(SLEEP_INTERVAL);
+            let now = Instant::now();
+
+            let due: Vec<MetricClass> = schedule
+                .iter()
+                .filter(|(_, sched)| sched.is_due(now))
+                .map(|(class, _)| *class)
+                .collect();
+            if due.is_empty() {
+                continue;
+            }
 
             // Parse current mountstats
             let current_mounts = match parse_mountstats(mountstats_path) {
@@ -127,55 +226,145 @@ impl Monitor {
                 }
             };
 
-            // Calculate elapsed time
-            let now = Instant::now();
-            let elapsed = now.duration_since(last_update);
-            let elapsed_seconds = elapsed.as_secs_f64();
-            last_update = now;
-
-            // Skip first iteration (no previous data)
-            if iteration == 0 {
-                for mount in &current_monitor_mounts {
-                    previous_mounts.insert(mount.mount_point.clone(), mount.clone());
-                }
-                iteration += 1;
-                continue;
-            }
-
-            // Clear screen if requested
             if clear_screen {
                 write!(writer, "\x1B[2J\x1B[1;1H")?;
             }
 
             let timestamp = Utc::now();
 
-            // Process each monitored mount
-            for current_mount in &current_monitor_mounts {
-                if let Some(previous_mount) = previous_mounts.get(&current_mount.mount_point) {
-                    // Calculate delta statistics
-                    let mut delta_stats =
-                        calculate_delta_stats(previous_mount, current_mount, elapsed_seconds);
-
-                    // Filter operations if specified
-                    delta_stats = filter_operations(delta_stats, &operations_filter);
+            if due.contains(&MetricClass::Io) {
+                let elapsed_seconds = schedule[&MetricClass::Io].elapsed_seconds(now);
+                schedule.get_mut(&MetricClass::Io).unwrap().mark_sampled(now);
+                #[cfg(target_os = "linux")]
+                let mut rpc_delta_retrans_total: i64 = 0;
+
+                for current_mount in &current_monitor_mounts {
+                    if let Some(previous_mount) = io_baseline.get(&current_mount.mount_point) {
+                        let mut delta_stats =
+                            calculate_delta_stats(previous_mount, current_mount, elapsed_seconds);
+                        delta_stats = filter_operations(delta_stats, &operations_selector);
+
+                        #[cfg(target_os = "linux")]
+                        {
+                            rpc_delta_retrans_total +=
+                                delta_stats.iter().map(|d| d.delta_retrans).sum::<i64>();
+                        }
+
+                        if !delta_stats.is_empty() {
+                            match output_format {
+                                OutputFormat::Json => {
+                                    display_stats_json(
+                                        writer,
+                                        current_mount,
+                                        &delta_stats,
+                                        &timestamp,
+                                    )?;
+                                }
+                                OutputFormat::Csv => {
+                                    display_stats_csv(
+                                        writer,
+                                        current_mount,
+                                        &delta_stats,
+                                        &timestamp,
+                                        &mut csv_header_written,
+                                    )?;
+                                }
+                                OutputFormat::NdJson => {
+                                    display_stats_ndjson(
+                                        writer,
+                                        current_mount,
+                                        &delta_stats,
+                                        show_bandwidth,
+                                        &timestamp,
+                                    )?;
+                                }
+                                OutputFormat::Table if iostat => {
+                                    let transport = calculate_transport_stats(
+                                        Some(previous_mount),
+                                        current_mount,
+                                        elapsed_seconds,
+                                    );
+                                    display_stats_iostat(
+                                        writer,
+                                        current_mount,
+                                        &delta_stats,
+                                        &transport,
+                                        &timestamp,
+                                    )?;
+                                }
+                                OutputFormat::Table => {
+                                    display_stats_simple(
+                                        writer,
+                                        current_mount,
+                                        &delta_stats,
+                                        show_bandwidth,
+                                        &timestamp,
+                                    )?;
+                                }
+                            }
+                        }
+                    }
+                    io_baseline.insert(current_mount.mount_point.clone(), current_mount.clone());
+                }
 
-                    // Display stats if we have any
-                    if !delta_stats.is_empty() {
-                        display_stats_simple(
+                #[cfg(target_os = "linux")]
+                {
+                    let current_network = read_network_snapshot();
+                    let context = compute_network_context(
+                        &network_baseline,
+                        &current_network,
+                        rpc_delta_retrans_total,
+                    );
+                    if context.network_implicated && output_format == OutputFormat::Table {
+                        writeln!(
                             writer,
-                            current_mount,
-                            &delta_stats,
-                            show_bandwidth,
-                            &timestamp,
+                            "note: RPC retransmits coincide with network-layer errors (tcp_retrans_segs={}, udp_errors={}, iface_drops={})",
+                            context.delta_tcp_retrans_segs,
+                            context.delta_udp_in_errors
+                                + context.delta_udp_rcvbuf_errors
+                                + context.delta_udp_sndbuf_errors,
+                            context.delta_iface_rx_drops + context.delta_iface_tx_drops
                         )?;
                     }
+                    network_baseline = current_network;
                 }
 
-                // Update previous mount data
-                previous_mounts.insert(current_mount.mount_point.clone(), current_mount.clone());
+                iteration += 1;
+            }
+
+            if due.contains(&MetricClass::Attr) {
+                schedule.get_mut(&MetricClass::Attr).unwrap().mark_sampled(now);
+
+                for current_mount in &current_monitor_mounts {
+                    if let Some(previous_mount) = attr_baseline.get(&current_mount.mount_point) {
+                        if let Some(events) =
+                            calculate_event_delta_stats(previous_mount, current_mount)
+                        {
+                            if output_format == OutputFormat::Table {
+                                display_attr_stats(writer, current_mount, &events)?;
+                            }
+                        }
+                    }
+                    attr_baseline.insert(current_mount.mount_point.clone(), current_mount.clone());
+                }
             }
 
-            iteration += 1;
+            if due.contains(&MetricClass::Caps) {
+                schedule.get_mut(&MetricClass::Caps).unwrap().mark_sampled(now);
+
+                if output_format == OutputFormat::Table {
+                    writeln!(writer, "Server capabilities:")?;
+                    for mount in &current_monitor_mounts {
+                        let names = mount.server_caps.names();
+                        if names.is_empty() {
+                            writeln!(writer, "  {}: (none reported)", mount.mount_point)?;
+                        } else {
+                            writeln!(writer, "  {}: {}", mount.mount_point, names)?;
+                        }
+                    }
+                    writeln!(writer)?;
+                }
+            }
         }
 
         Ok(())
@@ -191,8 +380,27 @@ impl Default for Monitor {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::types::NFSServerCaps;
     use std::collections::HashMap;
 
+    #[test]
+    fn test_sample_schedule_due_after_period_elapses() {
+        let schedule = SampleSchedule::new(Duration::from_secs(10));
+        assert!(!schedule.is_due(schedule.last_sampled));
+        assert!(schedule.is_due(schedule.last_sampled + Duration::from_secs(11)));
+    }
+
+    #[test]
+    fn test_sample_schedule_mark_sampled_resets_deadline() {
+        let mut schedule = SampleSchedule::new(Duration::from_secs(10));
+        let later = schedule.last_sampled + Duration::from_secs(11);
+        assert!(schedule.is_due(later));
+
+        schedule.mark_sampled(later);
+        assert!(!schedule.is_due(later));
+        assert!(schedule.is_due(later + Duration::from_secs(11)));
+    }
+
     fn create_test_mount(mount_point: &str, device: &str) -> NFSMount {
         NFSMount {
             device: device.to_string(),
@@ -204,6 +412,24 @@ mod tests {
             events: None,
             bytes_read: 0,
             bytes_write: 0,
+            direct_bytes_read: 0,
+            direct_bytes_write: 0,
+            server_bytes_read: 0,
+            server_bytes_write: 0,
+            read_pages: 0,
+            write_pages: 0,
+            mount_addr: None,
+            server_caps: NFSServerCaps::default(),
+            nfs_version: None,
+            proto: None,
+            xprt_proto: None,
+            xprt_sends: 0,
+            xprt_bklog_u: 0,
+            xprt_retrans: 0,
+            transport: None,
+            options: None,
+            fstype: None,
+            statvers: crate::types::MountstatsVersion::Unknown,
         }
     }
 
@@ -263,7 +489,8 @@ mod tests {
         ];
         let operations_filter = HashSet::new();
 
-        Monitor::print_initial_summary(&mut buffer, &None, &mounts, &operations_filter).unwrap();
+        Monitor::print_initial_summary(&mut buffer, &None, &mounts, &operations_filter, false)
+            .unwrap();
 
         let output = String::from_utf8(buffer).unwrap();
         assert!(output.contains("NFS I/O Statistics Monitor"));
@@ -285,6 +512,7 @@ mod tests {
             &Some("/mnt/nfs".to_string()),
             &mounts,
             &operations_filter,
+            false,
         )
         .unwrap();
 
@@ -292,4 +520,45 @@ mod tests {
         assert!(output.contains("Monitoring mount point: /mnt/nfs"));
         assert!(output.contains("Filtering operations"));
     }
+
+    #[test]
+    fn test_print_initial_summary_with_caps() {
+        let mut buffer = Vec::new();
+        let mut mount = create_test_mount("/mnt/nfs", "server:/export");
+        mount.server_caps = NFSServerCaps(NFSServerCaps::READDIRPLUS | NFSServerCaps::ACLS);
+        let mounts = vec![mount];
+        let operations_filter = HashSet::new();
+
+        Monitor::print_initial_summary(
+            &mut buffer,
+            &Some("/mnt/nfs".to_string()),
+            &mounts,
+            &operations_filter,
+            true,
+        )
+        .unwrap();
+
+        let output = String::from_utf8(buffer).unwrap();
+        assert!(output.contains("Server capabilities:"));
+        assert!(output.contains("/mnt/nfs: READDIRPLUS,ACLS"));
+    }
+
+    #[test]
+    fn test_print_initial_summary_caps_none_reported() {
+        let mut buffer = Vec::new();
+        let mounts = vec![create_test_mount("/mnt/nfs", "server:/export")];
+        let operations_filter = HashSet::new();
+
+        Monitor::print_initial_summary(
+            &mut buffer,
+            &Some("/mnt/nfs".to_string()),
+            &mounts,
+            &operations_filter,
+            true,
+        )
+        .unwrap();
+
+        let output = String::from_utf8(buffer).unwrap();
+        assert!(output.contains("/mnt/nfs: (none reported)"));
+    }
 }
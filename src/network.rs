@@ -0,0 +1,263 @@
+//! Correlates RPC retransmits (from mountstats) with kernel-level network
+//! counters, so operators can tell a flaky NIC or congested link apart from
+//! a slow NFS server. Parses the same aggregate counters system monitors
+//! use for this: `Tcp: RetransSegs` and `Udp: InErrors`/`RcvbufErrors`/
+//! `SndbufErrors` from `/proc/net/snmp`, plus per-interface rx/tx drops from
+//! `/proc/net/dev` (loopback excluded, since it can't explain RPC loss to a
+//! remote server).
+
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+
+/// A point-in-time snapshot of the kernel network counters this module
+/// tracks. All fields default to zero when the backing `/proc` file is
+/// missing or unreadable (e.g. a restricted container), so sampling never
+/// fails outright.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct NetworkSnapshot {
+    pub tcp_retrans_segs: i64,
+    pub udp_in_errors: i64,
+    pub udp_rcvbuf_errors: i64,
+    pub udp_sndbuf_errors: i64,
+    pub iface_rx_drops: i64,
+    pub iface_tx_drops: i64,
+}
+
+/// Delta of `NetworkSnapshot` counters between two samples, with a derived
+/// signal for whether the network stack looks implicated in this interval's
+/// RPC retransmits.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct NetworkContext {
+    pub delta_tcp_retrans_segs: i64,
+    pub delta_udp_in_errors: i64,
+    pub delta_udp_rcvbuf_errors: i64,
+    pub delta_udp_sndbuf_errors: i64,
+    pub delta_iface_rx_drops: i64,
+    pub delta_iface_tx_drops: i64,
+    /// True when this interval had RPC retransmits *and* at least one
+    /// network-layer counter moved, suggesting the retransmits coincide
+    /// with network trouble rather than a slow/overloaded server.
+    pub network_implicated: bool,
+}
+
+/// Read the current network snapshot from `/proc/net/snmp` and
+/// `/proc/net/dev`. Never fails: a missing or malformed proc file leaves the
+/// corresponding fields at zero.
+#[cfg(target_os = "linux")]
+pub fn read_network_snapshot() -> NetworkSnapshot {
+    let (tcp_retrans_segs, udp_in_errors, udp_rcvbuf_errors, udp_sndbuf_errors) =
+        File::open("/proc/net/snmp")
+            .ok()
+            .and_then(|f| parse_net_snmp(BufReader::new(f)))
+            .unwrap_or_default();
+
+    let (iface_rx_drops, iface_tx_drops) = File::open("/proc/net/dev")
+        .ok()
+        .map(|f| parse_net_dev(BufReader::new(f)))
+        .unwrap_or_default();
+
+    NetworkSnapshot {
+        tcp_retrans_segs,
+        udp_in_errors,
+        udp_rcvbuf_errors,
+        udp_sndbuf_errors,
+        iface_rx_drops,
+        iface_tx_drops,
+    }
+}
+
+/// Parse `/proc/net/snmp`'s `Tcp:`/`Udp:` header+value line pairs, pulling
+/// out `RetransSegs` and the UDP error counters by column name rather than
+/// position (the kernel has added columns to this file over the years).
+fn parse_net_snmp<R: BufRead>(reader: R) -> Option<(i64, i64, i64, i64)> {
+    let mut tcp_header: Option<String> = None;
+    let mut tcp_values: Option<String> = None;
+    let mut udp_header: Option<String> = None;
+    let mut udp_values: Option<String> = None;
+
+    for line in reader.lines().map_while(|l| l.ok()) {
+        if let Some(rest) = line.strip_prefix("Tcp:") {
+            if tcp_header.is_none() {
+                tcp_header = Some(rest.to_string());
+            } else {
+                tcp_values = Some(rest.to_string());
+            }
+        } else if let Some(rest) = line.strip_prefix("Udp:") {
+            if udp_header.is_none() {
+                udp_header = Some(rest.to_string());
+            } else {
+                udp_values = Some(rest.to_string());
+            }
+        }
+    }
+
+    let tcp_retrans_segs =
+        named_field(tcp_header.as_deref()?, tcp_values.as_deref()?, "RetransSegs").unwrap_or(0);
+    let udp_in_errors =
+        named_field(udp_header.as_deref()?, udp_values.as_deref()?, "InErrors").unwrap_or(0);
+    let udp_rcvbuf_errors =
+        named_field(udp_header.as_deref()?, udp_values.as_deref()?, "RcvbufErrors").unwrap_or(0);
+    let udp_sndbuf_errors =
+        named_field(udp_header.as_deref()?, udp_values.as_deref()?, "SndbufErrors").unwrap_or(0);
+
+    Some((
+        tcp_retrans_segs,
+        udp_in_errors,
+        udp_rcvbuf_errors,
+        udp_sndbuf_errors,
+    ))
+}
+
+/// Look up `field` in a `/proc/net/snmp` header/value line pair by matching
+/// column position between the whitespace-separated names and values.
+fn named_field(header: &str, values: &str, field: &str) -> Option<i64> {
+    let names: Vec<&str> = header.split_whitespace().collect();
+    let vals: Vec<&str> = values.split_whitespace().collect();
+    let index = names.iter().position(|name| *name == field)?;
+    vals.get(index)?.parse().ok()
+}
+
+/// Sum rx/tx drop counters across every interface in `/proc/net/dev` except
+/// loopback, which can't explain packet loss to a remote NFS server.
+fn parse_net_dev<R: BufRead>(reader: R) -> (i64, i64) {
+    let mut rx_drops = 0i64;
+    let mut tx_drops = 0i64;
+
+    for line in reader.lines().map_while(|l| l.ok()).skip(2) {
+        let Some((iface, rest)) = line.split_once(':') else {
+            continue;
+        };
+        if iface.trim() == "lo" {
+            continue;
+        }
+
+        let fields: Vec<&str> = rest.split_whitespace().collect();
+        // rx: bytes packets errs drop fifo frame compressed multicast (8)
+        // tx: bytes packets errs drop fifo colls carrier compressed (8)
+        if fields.len() < 16 {
+            continue;
+        }
+        rx_drops += fields[3].parse::<i64>().unwrap_or(0);
+        tx_drops += fields[11].parse::<i64>().unwrap_or(0);
+    }
+
+    (rx_drops, tx_drops)
+}
+
+/// Compute the network context for an interval, given the RPC retransmit
+/// delta already observed from mountstats (`DeltaStats::delta_retrans`
+/// summed across the operations of interest, or `TransportStats`-derived
+/// retransmits).
+pub fn compute_network_context(
+    previous: &NetworkSnapshot,
+    current: &NetworkSnapshot,
+    rpc_delta_retrans: i64,
+) -> NetworkContext {
+    let delta_tcp_retrans_segs = current.tcp_retrans_segs - previous.tcp_retrans_segs;
+    let delta_udp_in_errors = current.udp_in_errors - previous.udp_in_errors;
+    let delta_udp_rcvbuf_errors = current.udp_rcvbuf_errors - previous.udp_rcvbuf_errors;
+    let delta_udp_sndbuf_errors = current.udp_sndbuf_errors - previous.udp_sndbuf_errors;
+    let delta_iface_rx_drops = current.iface_rx_drops - previous.iface_rx_drops;
+    let delta_iface_tx_drops = current.iface_tx_drops - previous.iface_tx_drops;
+
+    let network_implicated = rpc_delta_retrans > 0
+        && (delta_tcp_retrans_segs > 0
+            || delta_udp_in_errors > 0
+            || delta_udp_rcvbuf_errors > 0
+            || delta_udp_sndbuf_errors > 0
+            || delta_iface_rx_drops > 0
+            || delta_iface_tx_drops > 0);
+
+    NetworkContext {
+        delta_tcp_retrans_segs,
+        delta_udp_in_errors,
+        delta_udp_rcvbuf_errors,
+        delta_udp_sndbuf_errors,
+        delta_iface_rx_drops,
+        delta_iface_tx_drops,
+        network_implicated,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    const SNMP_SAMPLE: &str = "\
+Ip: Forwarding DefaultTTL InReceives\n\
+Ip: 1 64 100\n\
+Tcp: RtoAlgorithm RtoMin RtoMax MaxConn ActiveOpens PassiveOpens AttemptFails EstabResets CurrEstab InSegs OutSegs RetransSegs InErrs OutRsts InCsumErrors\n\
+Tcp: 1 200 120000 -1 10 5 0 0 2 1000 900 42 0 0 0\n\
+Udp: InDatagrams NoPorts InErrors OutDatagrams RcvbufErrors SndbufErrors InCsumErrors IgnoredMulti\n\
+Udp: 500 0 7 480 3 1 0 0\n\
+";
+
+    const NET_DEV_SAMPLE: &str = "\
+Inter-|   Receive                                                |  Transmit\n\
+ face |bytes    packets errs drop fifo frame compressed multicast|bytes    packets errs drop fifo colls carrier compressed\n\
+    lo: 1000       10    0    0    0     0          0         0    1000       10    0    0    0     0       0          0\n\
+  eth0: 50000      500    0    9    0     0          0         0   40000      400    0    4    0     0       0          0\n\
+  eth1: 20000      200    0    1    0     0          0         0   15000      150    0    2    0     0       0          0\n\
+";
+
+    #[test]
+    fn test_parse_net_snmp_reads_named_columns() {
+        let (tcp_retrans, udp_in_errors, udp_rcvbuf, udp_sndbuf) =
+            parse_net_snmp(Cursor::new(SNMP_SAMPLE)).unwrap();
+
+        assert_eq!(tcp_retrans, 42);
+        assert_eq!(udp_in_errors, 7);
+        assert_eq!(udp_rcvbuf, 3);
+        assert_eq!(udp_sndbuf, 1);
+    }
+
+    #[test]
+    fn test_parse_net_snmp_missing_sections_returns_none() {
+        assert!(parse_net_snmp(Cursor::new("Ip: Forwarding\nIp: 1\n")).is_none());
+    }
+
+    #[test]
+    fn test_parse_net_dev_excludes_loopback_and_sums_interfaces() {
+        let (rx_drops, tx_drops) = parse_net_dev(Cursor::new(NET_DEV_SAMPLE));
+        assert_eq!(rx_drops, 9 + 1);
+        assert_eq!(tx_drops, 4 + 2);
+    }
+
+    #[test]
+    fn test_compute_network_context_implicated_when_retrans_and_network_errors_align() {
+        let previous = NetworkSnapshot::default();
+        let current = NetworkSnapshot {
+            tcp_retrans_segs: 5,
+            ..Default::default()
+        };
+
+        let context = compute_network_context(&previous, &current, 3);
+
+        assert_eq!(context.delta_tcp_retrans_segs, 5);
+        assert!(context.network_implicated);
+    }
+
+    #[test]
+    fn test_compute_network_context_not_implicated_without_rpc_retransmits() {
+        let previous = NetworkSnapshot::default();
+        let current = NetworkSnapshot {
+            tcp_retrans_segs: 5,
+            ..Default::default()
+        };
+
+        let context = compute_network_context(&previous, &current, 0);
+
+        assert!(!context.network_implicated);
+    }
+
+    #[test]
+    fn test_compute_network_context_not_implicated_without_network_errors() {
+        let previous = NetworkSnapshot::default();
+        let current = NetworkSnapshot::default();
+
+        let context = compute_network_context(&previous, &current, 10);
+
+        assert!(!context.network_implicated);
+    }
+}
@@ -1,4 +1,7 @@
-use crate::types::{NFSEvents, NFSMount, NFSOperation, NfsGazeError, Result};
+use crate::types::{
+    MountstatsVersion, NFSEvents, NFSMount, NFSMountOptions, NFSOperation, NFSServerCaps,
+    NFSTransport, NfsGazeError, Result,
+};
 use std::collections::HashMap;
 use std::fs::File;
 use std::io::{BufRead, BufReader, Read};
@@ -162,6 +165,17 @@ impl MountstatsParser {
             "/".to_string()
         };
 
+        let fstype = mount_info
+            .iter()
+            .position(|token| *token == "fstype")
+            .and_then(|index| mount_info.get(index + 1))
+            .map(|token| token.to_string());
+        let statvers = mount_info
+            .iter()
+            .find_map(|token| token.strip_prefix("statvers="))
+            .map(MountstatsVersion::parse)
+            .unwrap_or_default();
+
         let mount = NFSMount {
             device: server_export.to_string(),
             mount_point: mount_point.to_string(),
@@ -172,6 +186,24 @@ impl MountstatsParser {
             events: None,
             bytes_read: 0,
             bytes_write: 0,
+            direct_bytes_read: 0,
+            direct_bytes_write: 0,
+            server_bytes_read: 0,
+            server_bytes_write: 0,
+            read_pages: 0,
+            write_pages: 0,
+            mount_addr: None,
+            server_caps: NFSServerCaps::default(),
+            nfs_version: None,
+            proto: None,
+            xprt_proto: None,
+            xprt_sends: 0,
+            xprt_bklog_u: 0,
+            xprt_retrans: 0,
+            transport: None,
+            options: None,
+            fstype,
+            statvers,
         };
 
         self.mounts.insert(mount_point.to_string(), mount.clone());
@@ -186,6 +218,12 @@ impl MountstatsParser {
             self.parse_events_line(line)
         } else if line.starts_with("bytes:") {
             self.parse_bytes(line)
+        } else if line.starts_with("opts:") {
+            self.parse_mount_opts(line)
+        } else if line.starts_with("caps:") {
+            self.parse_caps(line)
+        } else if line.starts_with("xprt:") {
+            self.parse_xprt(line)
         } else if line.contains(':')
             && !line.starts_with("RPC")
             && !line.starts_with("xprt")
@@ -250,45 +288,231 @@ impl MountstatsParser {
         Ok(())
     }
 
+    /// Parse the `bytes:` line. The kernel emits up to 8 cumulative counters:
+    /// normal read/write bytes, O_DIRECT read/write bytes, server-side
+    /// (on-the-wire) read/write bytes, and read/write page counts. Older
+    /// kernels may emit fewer fields; anything missing defaults to 0.
     fn parse_bytes(&mut self, line: &str) -> Result<()> {
         let parts: Vec<&str> = line.split_whitespace().collect();
-        if parts.len() < 6 {
+        if parts.len() < 2 {
             return Err(NfsGazeError::ParseError(format!(
                 "Invalid bytes line: {}",
                 line
             )));
         }
 
-        if let Some(ref mut mount) = self.current_mount {
-            mount.bytes_read = parts[1]
-                .parse()
-                .map_err(|e| NfsGazeError::FieldParseError {
-                    field: "bytes_read".to_string(),
-                    source: e,
-                })?;
-            // Handle different formats - try both index 5 and 6
-            mount.bytes_write = if parts.len() > 6 && parts[6] != "0" {
-                parts[6]
-                    .parse()
-                    .map_err(|e| NfsGazeError::FieldParseError {
-                        field: "bytes_write".to_string(),
-                        source: e,
-                    })?
-            } else if parts.len() > 5 {
-                parts[5]
+        let parse_field = |index: usize, field: &str| -> Result<i64> {
+            match parts.get(index) {
+                Some(value) => value
                     .parse()
                     .map_err(|e| NfsGazeError::FieldParseError {
-                        field: "bytes_write".to_string(),
+                        field: field.to_string(),
                         source: e,
-                    })?
-            } else {
-                0
-            };
+                    }),
+                None => Ok(0),
+            }
+        };
+
+        if let Some(ref mut mount) = self.current_mount {
+            mount.bytes_read = parse_field(1, "bytes_read")?;
+            mount.bytes_write = parse_field(2, "bytes_write")?;
+            mount.direct_bytes_read = parse_field(3, "direct_bytes_read")?;
+            mount.direct_bytes_write = parse_field(4, "direct_bytes_write")?;
+            mount.server_bytes_read = parse_field(5, "server_bytes_read")?;
+            mount.server_bytes_write = parse_field(6, "server_bytes_write")?;
+            mount.read_pages = parse_field(7, "read_pages")?;
+            mount.write_pages = parse_field(8, "write_pages")?;
 
             // Update in mounts map
             if let Some(existing_mount) = self.mounts.get_mut(&mount.mount_point) {
                 existing_mount.bytes_read = mount.bytes_read;
                 existing_mount.bytes_write = mount.bytes_write;
+                existing_mount.direct_bytes_read = mount.direct_bytes_read;
+                existing_mount.direct_bytes_write = mount.direct_bytes_write;
+                existing_mount.server_bytes_read = mount.server_bytes_read;
+                existing_mount.server_bytes_write = mount.server_bytes_write;
+                existing_mount.read_pages = mount.read_pages;
+                existing_mount.write_pages = mount.write_pages;
+            }
+        }
+        Ok(())
+    }
+
+    /// Pull the server address, negotiated NFS version, and transport
+    /// protocol out of the `opts:` line's comma-separated `addr=`, `vers=`,
+    /// and `proto=` tokens, and also decode the full line into a structured
+    /// `NFSMountOptions`. The address distinguishes mounts sharing a
+    /// `server:/export` string but resolving to different backend IPs
+    /// (failover pairs, round-robin) so they don't collide in the exported
+    /// series; version and protocol feed the `nfs_mount_info` metric, and the
+    /// tuning fields (`rsize`/`wsize`/`timeo`/`retrans`) let consumers
+    /// correlate, e.g., an observed ShortRead spike with a small `rsize`.
+    fn parse_mount_opts(&mut self, line: &str) -> Result<()> {
+        let tokens: Vec<&str> = line
+            .strip_prefix("opts:")
+            .unwrap_or(line)
+            .split(',')
+            .map(|token| token.trim())
+            .filter(|token| !token.is_empty())
+            .collect();
+        let addr = tokens
+            .iter()
+            .find_map(|token| token.strip_prefix("addr="))
+            .map(|addr| addr.to_string());
+        let vers = tokens
+            .iter()
+            .find_map(|token| token.strip_prefix("vers="))
+            .map(|vers| vers.to_string());
+        let proto = tokens
+            .iter()
+            .find_map(|token| token.strip_prefix("proto="))
+            .map(|proto| proto.to_string());
+
+        let mut options = NFSMountOptions::default();
+        for token in &tokens {
+            if let Some((key, value)) = token.split_once('=') {
+                match key {
+                    "vers" => options.version = Some(value.to_string()),
+                    "rsize" => options.rsize = value.parse().ok(),
+                    "wsize" => options.wsize = value.parse().ok(),
+                    "timeo" => options.timeo = value.parse().ok(),
+                    "retrans" => options.retrans = value.parse().ok(),
+                    "proto" => options.proto = Some(value.to_string()),
+                    "sec" => options.sec = Some(value.to_string()),
+                    _ => {
+                        options.extra.insert(key.to_string(), value.to_string());
+                    }
+                }
+            } else {
+                options.flags.insert(token.to_string());
+            }
+        }
+
+        if let Some(ref mut mount) = self.current_mount {
+            mount.mount_addr = addr.clone();
+            mount.nfs_version = vers.clone();
+            mount.proto = proto.clone();
+            mount.options = Some(options.clone());
+
+            if let Some(existing_mount) = self.mounts.get_mut(&mount.mount_point) {
+                existing_mount.mount_addr = addr;
+                existing_mount.nfs_version = vers;
+                existing_mount.proto = proto;
+                existing_mount.options = Some(options);
+            }
+        }
+        Ok(())
+    }
+
+    /// Parse the `caps:` line's `caps=0x...` hex bitmask into an
+    /// `NFSServerCaps`.
+    fn parse_caps(&mut self, line: &str) -> Result<()> {
+        let caps = line
+            .strip_prefix("caps:")
+            .unwrap_or(line)
+            .split(',')
+            .find_map(|token| token.trim().strip_prefix("caps="))
+            .map(|hex| hex.strip_prefix("0x").unwrap_or(hex))
+            .and_then(|hex| u32::from_str_radix(hex, 16).ok());
+
+        let Some(caps) = caps else {
+            return Ok(());
+        };
+        let caps = NFSServerCaps(caps);
+
+        if let Some(ref mut mount) = self.current_mount {
+            mount.server_caps = caps;
+
+            if let Some(existing_mount) = self.mounts.get_mut(&mount.mount_point) {
+                existing_mount.server_caps = caps;
+            }
+        }
+        Ok(())
+    }
+
+    /// Parse the `xprt:` transport line. Its field layout depends on the
+    /// transport protocol (see the kernel's `xprt_udp_print_stats`/
+    /// `xprt_tcp_print_stats` in `net/sunrpc/xprtsock.c`): UDP emits
+    /// `port bind_count sends recvs bad_xids req_u bklog_u`, while TCP/RDMA
+    /// prepend connection bookkeeping, landing `sends`, `bad_xids`, and
+    /// `bklog_u` three fields further in. `sends` is the cumulative RPC call
+    /// count, `bad_xids` counts replies that arrived too late and had to be
+    /// retransmitted, and `bklog_u` is a running sum of the backlog queue
+    /// depth sampled at each send (divide by the delta of `sends` for the
+    /// average backlog length over a sampling window). The full field set is
+    /// additionally captured as a structured `NFSTransport` for consumers
+    /// that need more than the three flat summary fields above.
+    fn parse_xprt(&mut self, line: &str) -> Result<()> {
+        let parts: Vec<&str> = line.split_whitespace().collect();
+        if parts.len() < 2 {
+            return Ok(());
+        }
+
+        let proto = parts[1];
+        let numbers: Vec<i64> = parts[2..]
+            .iter()
+            .filter_map(|part| part.parse::<i64>().ok())
+            .collect();
+
+        let fields = match proto {
+            "udp" if numbers.len() >= 7 => Some((numbers[2], numbers[4], numbers[6])),
+            "tcp" | "rdma" if numbers.len() >= 13 => Some((numbers[5], numbers[7], numbers[9])),
+            _ => None,
+        };
+
+        let Some((sends, bad_xids, bklog_u)) = fields else {
+            return Ok(());
+        };
+
+        let transport = match proto {
+            "udp" if numbers.len() >= 7 => Some(NFSTransport {
+                protocol: proto.to_string(),
+                port: numbers[0],
+                bind_count: numbers[1],
+                connect_count: None,
+                connect_idle_time: None,
+                idle_time: 0,
+                sends: numbers[2],
+                receives: numbers[3],
+                bad_xids: numbers[4],
+                req_queue_time: numbers[5],
+                backlog_queue_wait: numbers[6],
+                max_slots: None,
+                sending_queue: None,
+                pending_queue: None,
+            }),
+            "tcp" | "rdma" if numbers.len() >= 13 => Some(NFSTransport {
+                protocol: proto.to_string(),
+                port: numbers[0],
+                bind_count: numbers[1],
+                connect_count: Some(numbers[2]),
+                connect_idle_time: Some(numbers[3]),
+                idle_time: numbers[4],
+                sends: numbers[5],
+                receives: numbers[6],
+                bad_xids: numbers[7],
+                req_queue_time: numbers[8],
+                backlog_queue_wait: numbers[9],
+                max_slots: Some(numbers[10]),
+                sending_queue: Some(numbers[11]),
+                pending_queue: Some(numbers[12]),
+            }),
+            _ => None,
+        };
+
+        if let Some(ref mut mount) = self.current_mount {
+            mount.xprt_proto = Some(proto.to_string());
+            mount.xprt_sends = sends;
+            mount.xprt_retrans = bad_xids;
+            mount.xprt_bklog_u = bklog_u;
+            mount.transport = transport.clone();
+
+            if let Some(existing_mount) = self.mounts.get_mut(&mount.mount_point) {
+                existing_mount.xprt_proto = mount.xprt_proto.clone();
+                existing_mount.xprt_sends = sends;
+                existing_mount.xprt_retrans = bad_xids;
+                existing_mount.xprt_bklog_u = bklog_u;
+                existing_mount.transport = transport;
             }
         }
         Ok(())
@@ -444,7 +668,8 @@ WRITE: 50 50 0 512 0 5 15 25 1
         assert_eq!(mount.export, "/export");
         assert_eq!(mount.age, 12345);
         assert_eq!(mount.bytes_read, 1048576);
-        assert_eq!(mount.bytes_write, 2097152);
+        assert_eq!(mount.bytes_write, 0);
+        assert_eq!(mount.server_bytes_write, 2097152);
         assert_eq!(mount.operations.len(), 2);
 
         let read_op = &mount.operations["READ"];
@@ -453,6 +678,151 @@ WRITE: 50 50 0 512 0 5 15 25 1
         assert_eq!(read_op.bytes_recv, 2048);
     }
 
+    #[test]
+    fn test_parse_mountstats_reader_opts_and_caps() {
+        let mountstats_data = r#"device server:/export mounted on /mnt/nfs with fstype nfs statvers=1.1
+opts: rw,vers=4.2,rsize=1048576,wsize=1048576,proto=tcp,addr=10.0.0.5
+caps: caps=0x3dff,wtmult=4096,dtsize=32768,bsize=0,namlen=255
+age: 12345
+READ: 100 95 5 1024 2048 10 20 30 2
+"#;
+
+        let cursor = Cursor::new(mountstats_data);
+        let mounts = parse_mountstats_reader(cursor).expect("Should parse mountstats");
+
+        let mount = &mounts["/mnt/nfs"];
+        assert_eq!(mount.mount_addr.as_deref(), Some("10.0.0.5"));
+        assert_eq!(mount.nfs_version.as_deref(), Some("4.2"));
+        assert_eq!(mount.proto.as_deref(), Some("tcp"));
+        assert!(mount.server_caps.contains(NFSServerCaps::READDIRPLUS));
+        assert!(mount.server_caps.contains(NFSServerCaps::ACLS));
+        assert!(!mount.server_caps.contains(NFSServerCaps::LAYOUTSTATS));
+
+        let options = mount.options.as_ref().expect("options should be set");
+        assert_eq!(options.version.as_deref(), Some("4.2"));
+        assert_eq!(options.rsize, Some(1048576));
+        assert_eq!(options.wsize, Some(1048576));
+        assert_eq!(options.proto.as_deref(), Some("tcp"));
+        assert!(options.flags.contains("rw"));
+        assert_eq!(options.extra.get("addr").map(String::as_str), Some("10.0.0.5"));
+    }
+
+    #[test]
+    fn test_parse_mount_opts_unknown_keys_land_in_extra() {
+        let mountstats_data = r#"device server:/export mounted on /mnt/nfs with fstype nfs statvers=1.1
+opts: rw,hard,noatime,vers=3,timeo=600,retrans=2,sec=sys,lookupcache=all
+age: 12345
+READ: 100 95 5 1024 2048 10 20 30 2
+"#;
+
+        let cursor = Cursor::new(mountstats_data);
+        let mounts = parse_mountstats_reader(cursor).expect("Should parse mountstats");
+
+        let mount = &mounts["/mnt/nfs"];
+        let options = mount.options.as_ref().expect("options should be set");
+        assert_eq!(options.version.as_deref(), Some("3"));
+        assert_eq!(options.timeo, Some(600));
+        assert_eq!(options.retrans, Some(2));
+        assert_eq!(options.sec.as_deref(), Some("sys"));
+        assert!(options.flags.contains("rw"));
+        assert!(options.flags.contains("hard"));
+        assert!(options.flags.contains("noatime"));
+        assert_eq!(
+            options.extra.get("lookupcache").map(String::as_str),
+            Some("all")
+        );
+        assert!(options.rsize.is_none());
+    }
+
+    #[test]
+    fn test_parse_mountstats_reader_xprt_tcp() {
+        let mountstats_data = r#"device server:/export mounted on /mnt/nfs with fstype nfs statvers=1.1
+age: 12345
+xprt: tcp 832 1 1 0 11 349 347 2 0 25 0 0 0
+READ: 100 95 5 1024 2048 10 20 30 2
+"#;
+
+        let cursor = Cursor::new(mountstats_data);
+        let mounts = parse_mountstats_reader(cursor).expect("Should parse mountstats");
+
+        let mount = &mounts["/mnt/nfs"];
+        assert_eq!(mount.xprt_proto.as_deref(), Some("tcp"));
+        assert_eq!(mount.xprt_sends, 349);
+        assert_eq!(mount.xprt_retrans, 2);
+        assert_eq!(mount.xprt_bklog_u, 25);
+
+        let transport = mount.transport.as_ref().expect("transport should be set");
+        assert_eq!(transport.protocol, "tcp");
+        assert_eq!(transport.port, 832);
+        assert_eq!(transport.bind_count, 1);
+        assert_eq!(transport.connect_count, Some(1));
+        assert_eq!(transport.connect_idle_time, Some(0));
+        assert_eq!(transport.idle_time, 11);
+        assert_eq!(transport.sends, 349);
+        assert_eq!(transport.receives, 347);
+        assert_eq!(transport.bad_xids, 2);
+        assert_eq!(transport.max_slots, Some(0));
+        assert!((transport.retransmit_ratio() - (2.0 / 349.0)).abs() < 1e-9);
+        assert!((transport.avg_backlog_wait() - (25.0 / 349.0)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_parse_mountstats_reader_xprt_udp_has_no_connection_fields() {
+        let mountstats_data = r#"device server:/export mounted on /mnt/nfs with fstype nfs statvers=1.1
+age: 12345
+xprt: udp 832 5 100 98 3 0 12
+READ: 100 95 5 1024 2048 10 20 30 2
+"#;
+
+        let cursor = Cursor::new(mountstats_data);
+        let mounts = parse_mountstats_reader(cursor).expect("Should parse mountstats");
+
+        let mount = &mounts["/mnt/nfs"];
+        let transport = mount.transport.as_ref().expect("transport should be set");
+        assert_eq!(transport.protocol, "udp");
+        assert_eq!(transport.port, 832);
+        assert_eq!(transport.bind_count, 5);
+        assert_eq!(transport.connect_count, None);
+        assert_eq!(transport.connect_idle_time, None);
+        assert_eq!(transport.max_slots, None);
+        assert_eq!(transport.sends, 100);
+        assert_eq!(transport.receives, 98);
+        assert_eq!(transport.bad_xids, 3);
+    }
+
+    #[test]
+    fn test_parse_device_line_captures_fstype_and_statvers() {
+        let mountstats_data = r#"device server:/export mounted on /mnt/nfs with fstype nfs4 statvers=1.1
+age: 12345
+READ: 100 95 5 1024 2048 10 20 30 2
+"#;
+
+        let cursor = Cursor::new(mountstats_data);
+        let mounts = parse_mountstats_reader(cursor).expect("Should parse mountstats");
+
+        let mount = &mounts["/mnt/nfs"];
+        assert_eq!(mount.fstype.as_deref(), Some("nfs4"));
+        assert_eq!(mount.statvers, MountstatsVersion::Known(1, 1));
+        assert!(mount.statvers.at_least(1, 1));
+        assert!(!mount.statvers.at_least(1, 2));
+    }
+
+    #[test]
+    fn test_parse_device_line_missing_statvers_defaults_to_unknown() {
+        let mountstats_data = r#"device server:/export mounted on /mnt/nfs with fstype nfs
+age: 12345
+READ: 100 95 5 1024 2048 10 20 30 2
+"#;
+
+        let cursor = Cursor::new(mountstats_data);
+        let mounts = parse_mountstats_reader(cursor).expect("Should parse mountstats");
+
+        let mount = &mounts["/mnt/nfs"];
+        assert_eq!(mount.fstype.as_deref(), Some("nfs"));
+        assert_eq!(mount.statvers, MountstatsVersion::Unknown);
+        assert!(!mount.statvers.at_least(1, 0));
+    }
+
     #[test]
     fn test_parse_mountstats_multiple_mounts() {
         let mountstats_data = r#"device server1:/export1 mounted on /mnt/nfs1 with fstype nfs statvers=1.1
@@ -1,4 +1,39 @@
-use crate::types::{DeltaStats, NFSMount, NFSOperation};
+use crate::types::{DeltaStats, EventDeltaStats, NFSMount, NFSOperation};
+use std::collections::{HashMap, VecDeque};
+use std::time::Duration;
+
+/// `2^32`, used to detect a 32-bit unsigned counter wrapping past `u32::MAX`
+/// while this process accumulates it into a wider `i64`.
+const COUNTER_WRAP: i64 = 1i64 << 32;
+
+/// Compute the delta of a monotonically increasing mountstats counter,
+/// handling the two discontinuities that show up in practice: a remount or
+/// server reboot (the counter drops back to near zero) and a 32-bit counter
+/// wrapping around. Mirrors the reset-aware delta logic used by process/CPU
+/// samplers that diff consecutive `/proc` snapshots.
+///
+/// When `current < previous`, wraparound is checked first, and only when
+/// it's actually plausible: both values have to fit in 32 bits *and*
+/// `previous` has to be near the top of that range, since a counter can't
+/// wrap past `u32::MAX` from somewhere in the middle of it. Everything else
+/// (including a drop from a `previous` that isn't near the boundary) is
+/// treated as a hard reset and the raw `current` value is used as this
+/// interval's delta (the counter effectively restarted from zero partway
+/// through the interval). Returns `(delta, reset_detected)`.
+fn counter_delta(previous: i64, current: i64) -> (i64, bool) {
+    if current >= previous {
+        return (current - previous, false);
+    }
+
+    let plausible_wrap =
+        previous < COUNTER_WRAP && current < COUNTER_WRAP && previous >= COUNTER_WRAP / 2;
+
+    if plausible_wrap {
+        (current + (COUNTER_WRAP - previous), true)
+    } else {
+        (current, true)
+    }
+}
 
 /// Calculate delta statistics between two measurements
 pub fn calculate_delta_stats(
@@ -40,21 +75,183 @@ pub fn calculate_delta_stats(
     deltas
 }
 
+/// Per-operation deltas for a single mount, produced by [`diff`]ing two full
+/// mountstats snapshots.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MountstatsDelta {
+    pub mount_point: String,
+    pub operations: Vec<DeltaStats>,
+}
+
+/// Diff two full mountstats snapshots (as returned by consecutive
+/// `parse_mountstats` calls) into per-mount, per-operation rates. This is
+/// the computation layer the `--interval`/`--count` polling loop needs,
+/// keeping rate math out of the display code.
+///
+/// Mounts and operations are matched by key; a mount or operation present in
+/// only one snapshot is skipped rather than reported, since there's no
+/// meaningful rate to compute across a mount that just appeared or vanished.
+/// Unlike the live monitoring loop's [`calculate_delta_stats`], which uses
+/// [`counter_delta`]'s reset-aware wraparound math, `diff` clamps any
+/// negative per-counter delta to zero and marks `reset_detected` instead: a
+/// remount between the two snapshots being diffed yields a zero (not
+/// negative or wrapped) delta for that interval rather than a guess.
+pub fn diff(
+    previous: &HashMap<String, NFSMount>,
+    current: &HashMap<String, NFSMount>,
+    elapsed: Duration,
+) -> Vec<MountstatsDelta> {
+    let elapsed_seconds = elapsed.as_secs_f64();
+    let mut deltas = Vec::new();
+
+    for (mount_point, current_mount) in current {
+        let Some(previous_mount) = previous.get(mount_point) else {
+            continue;
+        };
+
+        let mut operations: Vec<DeltaStats> = Vec::new();
+        for (op_name, current_op) in &current_mount.operations {
+            if let Some(previous_op) = previous_mount.operations.get(op_name) {
+                let delta = calculate_operation_delta_clamped(previous_op, current_op, elapsed_seconds);
+                if delta.delta_ops > 0 || delta.reset_detected {
+                    operations.push(delta);
+                }
+            }
+        }
+        operations.sort_by(|a, b| a.operation.cmp(&b.operation));
+
+        deltas.push(MountstatsDelta {
+            mount_point: mount_point.clone(),
+            operations,
+        });
+    }
+
+    deltas.sort_by(|a, b| a.mount_point.cmp(&b.mount_point));
+    deltas
+}
+
+/// Like [`counter_delta`], but for callers that want a counter reset
+/// reported as a zero delta rather than reconstructed via the reset/wrap
+/// heuristic. Returns `(delta, reset_detected)`.
+fn clamped_delta(previous: i64, current: i64) -> (i64, bool) {
+    if current >= previous {
+        (current - previous, false)
+    } else {
+        (0, true)
+    }
+}
+
+/// [`calculate_operation_delta`]'s clamp-to-zero counterpart, used by
+/// [`diff`] so a counter reset between the two diffed snapshots can't
+/// produce a nonsense rate.
+fn calculate_operation_delta_clamped(
+    previous: &NFSOperation,
+    current: &NFSOperation,
+    elapsed_seconds: f64,
+) -> DeltaStats {
+    let (delta_ops, ops_reset) = clamped_delta(previous.ops, current.ops);
+    let (delta_sent, sent_reset) = clamped_delta(previous.bytes_sent, current.bytes_sent);
+    let (delta_recv, recv_reset) = clamped_delta(previous.bytes_recv, current.bytes_recv);
+    let delta_bytes = delta_sent + delta_recv;
+    let (delta_rtt, rtt_reset) = clamped_delta(previous.rtt, current.rtt);
+    let (delta_exec, exec_reset) = clamped_delta(previous.execute_time, current.execute_time);
+    let (delta_queue, queue_reset) = clamped_delta(previous.queue_time, current.queue_time);
+    let (delta_errors, errors_reset) = clamped_delta(previous.errors, current.errors);
+    let (delta_retrans, retrans_reset) = clamped_delta(previous.timeouts, current.timeouts);
+    let (delta_ntrans, ntrans_reset) = clamped_delta(previous.ntrans, current.ntrans);
+    let reset_detected = ops_reset
+        || sent_reset
+        || recv_reset
+        || rtt_reset
+        || exec_reset
+        || queue_reset
+        || errors_reset
+        || retrans_reset
+        || ntrans_reset;
+
+    let iops = if elapsed_seconds > 0.0 {
+        delta_ops as f64 / elapsed_seconds
+    } else {
+        0.0
+    };
+
+    let avg_rtt = if delta_ops > 0 {
+        delta_rtt as f64 / delta_ops as f64
+    } else {
+        0.0
+    };
+
+    let avg_exec = if delta_ops > 0 {
+        delta_exec as f64 / delta_ops as f64
+    } else {
+        0.0
+    };
+
+    let avg_queue = if delta_ops > 0 {
+        delta_queue as f64 / delta_ops as f64
+    } else {
+        0.0
+    };
+
+    let kb_per_op = if delta_ops > 0 {
+        (delta_bytes as f64 / 1024.0) / delta_ops as f64
+    } else {
+        0.0
+    };
+
+    let kb_per_sec = if elapsed_seconds > 0.0 {
+        (delta_bytes as f64 / 1024.0) / elapsed_seconds
+    } else {
+        0.0
+    };
+
+    DeltaStats {
+        operation: current.name.clone(),
+        delta_ops,
+        delta_bytes,
+        delta_sent,
+        delta_recv,
+        delta_rtt,
+        delta_exec,
+        delta_queue,
+        delta_errors,
+        delta_retrans,
+        delta_ntrans,
+        avg_rtt,
+        avg_exec,
+        avg_queue,
+        kb_per_op,
+        kb_per_sec,
+        iops,
+        reset_detected,
+    }
+}
+
 /// Calculate delta statistics for a single operation
 fn calculate_operation_delta(
     previous: &NFSOperation,
     current: &NFSOperation,
     elapsed_seconds: f64,
 ) -> DeltaStats {
-    let delta_ops = current.ops - previous.ops;
-    let delta_sent = current.bytes_sent - previous.bytes_sent;
-    let delta_recv = current.bytes_recv - previous.bytes_recv;
+    let (delta_ops, ops_reset) = counter_delta(previous.ops, current.ops);
+    let (delta_sent, sent_reset) = counter_delta(previous.bytes_sent, current.bytes_sent);
+    let (delta_recv, recv_reset) = counter_delta(previous.bytes_recv, current.bytes_recv);
     let delta_bytes = delta_sent + delta_recv;
-    let delta_rtt = current.rtt - previous.rtt;
-    let delta_exec = current.execute_time - previous.execute_time;
-    let delta_queue = current.queue_time - previous.queue_time;
-    let delta_errors = current.errors - previous.errors;
-    let delta_retrans = current.timeouts - previous.timeouts;
+    let (delta_rtt, rtt_reset) = counter_delta(previous.rtt, current.rtt);
+    let (delta_exec, exec_reset) = counter_delta(previous.execute_time, current.execute_time);
+    let (delta_queue, queue_reset) = counter_delta(previous.queue_time, current.queue_time);
+    let (delta_errors, errors_reset) = counter_delta(previous.errors, current.errors);
+    let (delta_retrans, retrans_reset) = counter_delta(previous.timeouts, current.timeouts);
+    let (delta_ntrans, ntrans_reset) = counter_delta(previous.ntrans, current.ntrans);
+    let reset_detected = ops_reset
+        || sent_reset
+        || recv_reset
+        || rtt_reset
+        || exec_reset
+        || queue_reset
+        || errors_reset
+        || retrans_reset
+        || ntrans_reset;
 
     // Calculate averages and rates
     let iops = if elapsed_seconds > 0.0 {
@@ -104,29 +301,372 @@ fn calculate_operation_delta(
         delta_queue,
         delta_errors,
         delta_retrans,
+        delta_ntrans,
         avg_rtt,
         avg_exec,
         avg_queue,
         kb_per_op,
         kb_per_sec,
         iops,
+        reset_detected,
     }
 }
 
-/// Filter operations based on a set of allowed operation names
-pub fn filter_operations(stats: Vec<DeltaStats>, filter: &std::collections::HashSet<String>) -> Vec<DeltaStats> {
-    if filter.is_empty() {
-        stats
+/// Calculate attribute-cache efficiency deltas from the `events:` line
+/// between two samples. Returns `None` if either sample is missing its
+/// events (older kernels, or a mount snapshot taken before the first
+/// `events:` line was parsed).
+///
+/// The mountstats event list has no dedicated "getattr" counter; the
+/// closest analog the kernel exposes is `vfs_access`, since attribute
+/// cache checks on a cached inode are driven by the same revalidation
+/// path as access checks. `attr_cache_hit_pct` is therefore
+/// `(1 - inode_revalidate / vfs_access) * 100`: the percentage of access-triggered
+/// revalidations that were satisfied from cache rather than round-tripping
+/// to the server.
+pub fn calculate_event_delta_stats(
+    previous: &NFSMount,
+    current: &NFSMount,
+) -> Option<EventDeltaStats> {
+    let previous_events = previous.events.as_ref()?;
+    let current_events = current.events.as_ref()?;
+
+    let attr_invalidate = current_events.attr_invalidate - previous_events.attr_invalidate;
+    let inode_revalidate = current_events.inode_revalidate - previous_events.inode_revalidate;
+    let dentry_revalidate = current_events.dentry_revalidate - previous_events.dentry_revalidate;
+    let data_invalidate = current_events.data_invalidate - previous_events.data_invalidate;
+    let vfs_access = current_events.vfs_access - previous_events.vfs_access;
+    let vfs_open = current_events.vfs_open - previous_events.vfs_open;
+    let vfs_lookup = current_events.vfs_lookup - previous_events.vfs_lookup;
+    let vfs_getdents = current_events.vfs_getdents - previous_events.vfs_getdents;
+
+    let attr_cache_hit_pct = if vfs_access > 0 {
+        (1.0 - inode_revalidate as f64 / vfs_access as f64) * 100.0
+    } else {
+        0.0
+    };
+
+    Some(EventDeltaStats {
+        attr_invalidate,
+        inode_revalidate,
+        dentry_revalidate,
+        data_invalidate,
+        vfs_access,
+        vfs_open,
+        vfs_lookup,
+        vfs_getdents,
+        attr_cache_hit_pct,
+    })
+}
+
+/// Transport-level statistics for the classic `nfs-iostat` view, derived
+/// from the mountstats `xprt:` line rather than per-operation RPC stats.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TransportStats {
+    pub ops_per_sec: f64,
+    pub backlog_avg: f64,
+    pub retrans_pct: f64,
+}
+
+/// Calculate transport-level (RPC send) statistics between two
+/// measurements. `ops_per_sec` is the delta of the cumulative RPC send
+/// counter divided by elapsed time, including a real zero when a prior
+/// sample exists but no new sends happened (a genuinely idle interval);
+/// only when there's no prior sample to diff against at all (e.g. the
+/// first iteration) does it fall back to the cumulative `sends` divided
+/// by the mount's `age` for a long-run average.
+pub fn calculate_transport_stats(
+    previous: Option<&NFSMount>,
+    current: &NFSMount,
+    elapsed_seconds: f64,
+) -> TransportStats {
+    let (delta_sends, delta_bklog, delta_retrans) = match previous {
+        Some(previous) => (
+            current.xprt_sends - previous.xprt_sends,
+            current.xprt_bklog_u - previous.xprt_bklog_u,
+            current.xprt_retrans - previous.xprt_retrans,
+        ),
+        None => (0, 0, 0),
+    };
+
+    // Only fall back to the lifetime cumulative rate when there was no prior
+    // sample to diff against at all (the first poll of a mount). Once we
+    // have a prior sample, a delta of zero sends is a real zero-rate idle
+    // interval, not a reason to fall back to the cumulative average.
+    let ops_per_sec = if previous.is_some() {
+        if elapsed_seconds > 0.0 {
+            delta_sends as f64 / elapsed_seconds
+        } else {
+            0.0
+        }
+    } else if current.age > 0 {
+        current.xprt_sends as f64 / current.age as f64
+    } else {
+        0.0
+    };
+
+    let backlog_avg = if delta_sends > 0 {
+        delta_bklog as f64 / delta_sends as f64
     } else {
-        stats.into_iter()
-            .filter(|stat| filter.contains(&stat.operation))
-            .collect()
+        0.0
+    };
+
+    let retrans_pct = if delta_sends > 0 {
+        delta_retrans as f64 / delta_sends as f64 * 100.0
+    } else {
+        0.0
+    };
+
+    TransportStats {
+        ops_per_sec,
+        backlog_avg,
+        retrans_pct,
+    }
+}
+
+/// How `RateSmoother` aggregates raw per-interval rates across poll cycles.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SmoothingMode {
+    /// Arithmetic mean of the last `window` raw samples.
+    Window(usize),
+    /// Exponentially weighted moving average with `alpha = 2 / (window + 1)`,
+    /// the standard EWMA-to-window-size conversion.
+    Ewma(usize),
+}
+
+/// The subset of `DeltaStats` fields that jitter at short poll intervals and
+/// benefit from smoothing. Per-interval "raw" fields like `delta_ops` are
+/// left untouched by `RateSmoother`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct SmoothedRates {
+    iops: f64,
+    kb_per_sec: f64,
+    avg_rtt: f64,
+    avg_exec: f64,
+}
+
+impl SmoothedRates {
+    fn from_stat(stat: &DeltaStats) -> Self {
+        Self {
+            iops: stat.iops,
+            kb_per_sec: stat.kb_per_sec,
+            avg_rtt: stat.avg_rtt,
+            avg_exec: stat.avg_exec,
+        }
+    }
+
+    fn mean(samples: &VecDeque<SmoothedRates>) -> Self {
+        let n = samples.len() as f64;
+        let sum = samples.iter().fold(
+            SmoothedRates {
+                iops: 0.0,
+                kb_per_sec: 0.0,
+                avg_rtt: 0.0,
+                avg_exec: 0.0,
+            },
+            |acc, r| SmoothedRates {
+                iops: acc.iops + r.iops,
+                kb_per_sec: acc.kb_per_sec + r.kb_per_sec,
+                avg_rtt: acc.avg_rtt + r.avg_rtt,
+                avg_exec: acc.avg_exec + r.avg_exec,
+            },
+        );
+        SmoothedRates {
+            iops: sum.iops / n,
+            kb_per_sec: sum.kb_per_sec / n,
+            avg_rtt: sum.avg_rtt / n,
+            avg_exec: sum.avg_exec / n,
+        }
+    }
+
+    fn ewma(self, previous: SmoothedRates, alpha: f64) -> Self {
+        SmoothedRates {
+            iops: alpha * self.iops + (1.0 - alpha) * previous.iops,
+            kb_per_sec: alpha * self.kb_per_sec + (1.0 - alpha) * previous.kb_per_sec,
+            avg_rtt: alpha * self.avg_rtt + (1.0 - alpha) * previous.avg_rtt,
+            avg_exec: alpha * self.avg_exec + (1.0 - alpha) * previous.avg_exec,
+        }
     }
 }
 
+/// Per-operation smoothing state held by `RateSmoother` between polls.
+#[derive(Debug, Default)]
+struct OperationSmoother {
+    window: VecDeque<SmoothedRates>,
+    ewma: Option<SmoothedRates>,
+}
+
+/// Stateful smoothing aggregator for `DeltaStats` rates, kept alive across
+/// poll cycles (one entry per operation name) so that instantaneous
+/// per-interval jitter doesn't dominate the displayed `iops`/`kb_per_sec`/
+/// `avg_rtt`/`avg_exec` at short `--interval` values.
+///
+/// Operations that stop appearing in a poll's `DeltaStats` are dropped from
+/// the smoother's state on that same call, so a remounted or idle operation
+/// doesn't linger with stale smoothed numbers; an operation seen for the
+/// first time is seeded with its own raw sample rather than averaged against
+/// zero.
+pub struct RateSmoother {
+    mode: SmoothingMode,
+    alpha: f64,
+    state: HashMap<String, OperationSmoother>,
+}
+
+impl RateSmoother {
+    pub fn new(mode: SmoothingMode) -> Self {
+        let alpha = match mode {
+            SmoothingMode::Window(_) => 0.0,
+            SmoothingMode::Ewma(window) => 2.0 / (window.max(1) as f64 + 1.0),
+        };
+        Self {
+            mode,
+            alpha,
+            state: HashMap::new(),
+        }
+    }
+
+    /// Smooth a fresh batch of per-interval `DeltaStats`, returning one
+    /// smoothed `DeltaStats` per input with the jitter-prone rate fields
+    /// replaced by their moving average and every other field (including
+    /// the raw per-interval deltas) preserved unchanged.
+    pub fn smooth(&mut self, raw: Vec<DeltaStats>) -> Vec<DeltaStats> {
+        let seen: std::collections::HashSet<String> =
+            raw.iter().map(|stat| stat.operation.clone()).collect();
+
+        let smoothed = raw
+            .into_iter()
+            .map(|stat| {
+                let sample = SmoothedRates::from_stat(&stat);
+                let entry = self.state.entry(stat.operation.clone()).or_default();
+
+                let rates = match self.mode {
+                    SmoothingMode::Window(window) => {
+                        entry.window.push_back(sample);
+                        while entry.window.len() > window.max(1) {
+                            entry.window.pop_front();
+                        }
+                        SmoothedRates::mean(&entry.window)
+                    }
+                    SmoothingMode::Ewma(_) => {
+                        let next = match entry.ewma {
+                            Some(previous) => sample.ewma(previous, self.alpha),
+                            None => sample,
+                        };
+                        entry.ewma = Some(next);
+                        next
+                    }
+                };
+
+                DeltaStats {
+                    iops: rates.iops,
+                    kb_per_sec: rates.kb_per_sec,
+                    avg_rtt: rates.avg_rtt,
+                    avg_exec: rates.avg_exec,
+                    ..stat
+                }
+            })
+            .collect();
+
+        self.state.retain(|op, _| seen.contains(op));
+        smoothed
+    }
+}
+
+/// How `filter_operations` picks which operations to display.
+#[derive(Debug, Clone)]
+pub enum OperationSelector {
+    /// Exact operation names (current/original behavior). An empty set
+    /// passes every operation through unchanged.
+    Names(std::collections::HashSet<String>),
+    /// Shell-style glob patterns (`*` wildcard), e.g. `READ*` or
+    /// `*GETATTR`. An operation is kept if it matches any pattern. An
+    /// empty list passes every operation through unchanged.
+    Globs(Vec<String>),
+    /// Keep only the `n` operations with the highest `metric` value this
+    /// interval, applied before the existing alphabetical sort.
+    TopN {
+        metric: crate::alerts::ThresholdMetric,
+        n: usize,
+    },
+}
+
+/// Match a single shell-style glob pattern (only `*` wildcards are
+/// supported) against `value`.
+fn glob_match(pattern: &str, value: &str) -> bool {
+    let segments: Vec<&str> = pattern.split('*').collect();
+    if segments.len() == 1 {
+        return pattern == value;
+    }
+
+    let mut rest = value;
+    for (i, segment) in segments.iter().enumerate() {
+        if segment.is_empty() {
+            continue;
+        }
+        if i == 0 {
+            if !rest.starts_with(segment) {
+                return false;
+            }
+            rest = &rest[segment.len()..];
+        } else if i == segments.len() - 1 {
+            return rest.ends_with(segment);
+        } else {
+            match rest.find(segment) {
+                Some(pos) => rest = &rest[pos + segment.len()..],
+                None => return false,
+            }
+        }
+    }
+    true
+}
+
+/// Filter and/or rank operations for display according to `selector`, then
+/// sort the result by operation name for consistent output (top-N ranking
+/// happens first, so ties in the final alphabetical order only occur among
+/// operations that made the cut).
+pub fn filter_operations(stats: Vec<DeltaStats>, selector: &OperationSelector) -> Vec<DeltaStats> {
+    let mut filtered = match selector {
+        OperationSelector::Names(names) => {
+            if names.is_empty() {
+                stats
+            } else {
+                stats
+                    .into_iter()
+                    .filter(|stat| names.contains(&stat.operation))
+                    .collect()
+            }
+        }
+        OperationSelector::Globs(patterns) => {
+            if patterns.is_empty() {
+                stats
+            } else {
+                stats
+                    .into_iter()
+                    .filter(|stat| patterns.iter().any(|p| glob_match(p, &stat.operation)))
+                    .collect()
+            }
+        }
+        OperationSelector::TopN { metric, n } => {
+            let mut ranked = stats;
+            ranked.sort_by(|a, b| {
+                metric
+                    .value(b)
+                    .partial_cmp(&metric.value(a))
+                    .unwrap_or(std::cmp::Ordering::Equal)
+            });
+            ranked.truncate(*n);
+            ranked
+        }
+    };
+
+    filtered.sort_by(|a, b| a.operation.cmp(&b.operation));
+    filtered
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::types::{NFSEvents, NFSServerCaps};
     use std::collections::{HashMap, HashSet};
 
     fn create_test_mount(operations: HashMap<String, NFSOperation>) -> NFSMount {
@@ -140,6 +680,24 @@ mod tests {
             events: None,
             bytes_read: 0,
             bytes_write: 0,
+            direct_bytes_read: 0,
+            direct_bytes_write: 0,
+            server_bytes_read: 0,
+            server_bytes_write: 0,
+            read_pages: 0,
+            write_pages: 0,
+            mount_addr: None,
+            server_caps: NFSServerCaps::default(),
+            nfs_version: None,
+            proto: None,
+            xprt_proto: None,
+            xprt_sends: 0,
+            xprt_bklog_u: 0,
+            xprt_retrans: 0,
+            transport: None,
+            options: None,
+            fstype: None,
+            statvers: crate::types::MountstatsVersion::Unknown,
         }
     }
 
@@ -179,6 +737,59 @@ mod tests {
         assert_eq!(delta.iops, 100.0);
         assert_eq!(delta.avg_rtt, 10.0); // delta_rtt / delta_ops
         assert_eq!(delta.avg_exec, 20.0); // delta_exec / delta_ops
+        assert!(!delta.reset_detected);
+    }
+
+    #[test]
+    fn test_counter_delta_normal_no_reset() {
+        let (delta, reset) = counter_delta(100, 250);
+        assert_eq!(delta, 150);
+        assert!(!reset);
+    }
+
+    #[test]
+    fn test_counter_delta_remount_to_zero() {
+        // Counter resets to a small value after a remount/reboot: `previous`
+        // is nowhere near the top of the 32-bit range, so this can't be a
+        // wrap and the raw current value is used.
+        let (delta, reset) = counter_delta(10_000, 5);
+        assert_eq!(delta, 5);
+        assert!(reset);
+    }
+
+    #[test]
+    fn test_counter_delta_partial_rollback() {
+        // A small backwards step that isn't a plausible 32-bit wrap (values
+        // are well above u32 range) still falls back to the raw current
+        // value rather than reporting a negative delta.
+        let previous = (1i64 << 33) + 1000;
+        let current = (1i64 << 33) + 10;
+        let (delta, reset) = counter_delta(previous, current);
+        assert_eq!(delta, current);
+        assert!(reset);
+    }
+
+    #[test]
+    fn test_counter_delta_32bit_wraparound() {
+        // Counter was close to u32::MAX and wrapped; `previous` is in the
+        // upper half of the 32-bit range and both values fit in 32 bits, so
+        // this is treated as wraparound rather than a hard reset.
+        let previous = u32::MAX as i64 - 100;
+        let current = 50i64;
+        let (delta, reset) = counter_delta(previous, current);
+        assert_eq!(delta, current + (COUNTER_WRAP - previous));
+        assert!(reset);
+    }
+
+    #[test]
+    fn test_calculate_operation_delta_flags_reset() {
+        let previous = create_test_operation("READ", 10_000, 0, 0, 0, 0);
+        let current = create_test_operation("READ", 5, 0, 0, 0, 0);
+
+        let delta = calculate_operation_delta(&previous, &current, 1.0);
+
+        assert_eq!(delta.delta_ops, 5);
+        assert!(delta.reset_detected);
     }
 
     #[test]
@@ -200,6 +811,169 @@ mod tests {
         assert_eq!(delta.iops, 100.0);
     }
 
+    #[test]
+    fn test_calculate_transport_stats() {
+        let mut previous = create_test_mount(HashMap::new());
+        previous.xprt_sends = 1000;
+        previous.xprt_bklog_u = 500;
+        previous.xprt_retrans = 10;
+
+        let mut current = previous.clone();
+        current.xprt_sends = 1100;
+        current.xprt_bklog_u = 600;
+        current.xprt_retrans = 15;
+
+        let transport = calculate_transport_stats(Some(&previous), &current, 2.0);
+
+        assert_eq!(transport.ops_per_sec, 50.0); // 100 sends / 2s
+        assert_eq!(transport.backlog_avg, 1.0); // 100 backlog / 100 sends
+        assert_eq!(transport.retrans_pct, 5.0); // 5 retrans / 100 sends * 100
+    }
+
+    #[test]
+    fn test_calculate_transport_stats_falls_back_to_age() {
+        let mut current = create_test_mount(HashMap::new());
+        current.age = 100;
+        current.xprt_sends = 500;
+
+        let transport = calculate_transport_stats(None, &current, 1.0);
+
+        assert_eq!(transport.ops_per_sec, 5.0); // 500 sends / 100s age
+        assert_eq!(transport.backlog_avg, 0.0);
+        assert_eq!(transport.retrans_pct, 0.0);
+    }
+
+    #[test]
+    fn test_calculate_transport_stats_idle_interval_with_prior_sample_is_zero() {
+        let mut previous = create_test_mount(HashMap::new());
+        previous.age = 100;
+        previous.xprt_sends = 500;
+
+        let mut current = previous.clone();
+        current.age = 101;
+        // No new sends this interval, but there was a real prior sample, so
+        // this must read as a zero rate rather than falling back to the
+        // lifetime cumulative average (500 / 101).
+        assert_eq!(current.xprt_sends, previous.xprt_sends);
+
+        let transport = calculate_transport_stats(Some(&previous), &current, 1.0);
+
+        assert_eq!(transport.ops_per_sec, 0.0);
+        assert_eq!(transport.backlog_avg, 0.0);
+        assert_eq!(transport.retrans_pct, 0.0);
+    }
+
+    #[test]
+    fn test_calculate_event_delta_stats() {
+        let mut previous = create_test_mount(HashMap::new());
+        previous.events = Some(NFSEvents {
+            inode_revalidate: 100,
+            vfs_access: 200,
+            attr_invalidate: 10,
+            dentry_revalidate: 50,
+            data_invalidate: 5,
+            vfs_open: 20,
+            vfs_lookup: 30,
+            vfs_getdents: 2,
+            ..Default::default()
+        });
+
+        let mut current = previous.clone();
+        current.events = Some(NFSEvents {
+            inode_revalidate: 150,
+            vfs_access: 400,
+            attr_invalidate: 15,
+            dentry_revalidate: 80,
+            data_invalidate: 8,
+            vfs_open: 25,
+            vfs_lookup: 45,
+            vfs_getdents: 4,
+            ..Default::default()
+        });
+
+        let deltas = calculate_event_delta_stats(&previous, &current).unwrap();
+
+        assert_eq!(deltas.inode_revalidate, 50);
+        assert_eq!(deltas.vfs_access, 200);
+        assert_eq!(deltas.attr_invalidate, 5);
+        assert_eq!(deltas.attr_cache_hit_pct, 75.0); // (1 - 50/200) * 100
+    }
+
+    #[test]
+    fn test_calculate_event_delta_stats_missing_events() {
+        let previous = create_test_mount(HashMap::new());
+        let current = create_test_mount(HashMap::new());
+
+        assert!(calculate_event_delta_stats(&previous, &current).is_none());
+    }
+
+    fn create_rate_stat(operation: &str, iops: f64) -> DeltaStats {
+        DeltaStats {
+            operation: operation.to_string(),
+            delta_ops: iops as i64,
+            delta_bytes: 0,
+            delta_sent: 0,
+            delta_recv: 0,
+            delta_rtt: 0,
+            delta_exec: 0,
+            delta_queue: 0,
+            delta_errors: 0,
+            delta_retrans: 0,
+            delta_ntrans: 0,
+            avg_rtt: iops,
+            avg_exec: iops,
+            avg_queue: 0.0,
+            kb_per_op: 0.0,
+            kb_per_sec: iops,
+            iops,
+            reset_detected: false,
+        }
+    }
+
+    #[test]
+    fn test_rate_smoother_window_mean_of_last_n() {
+        let mut smoother = RateSmoother::new(SmoothingMode::Window(3));
+
+        smoother.smooth(vec![create_rate_stat("READ", 10.0)]);
+        smoother.smooth(vec![create_rate_stat("READ", 20.0)]);
+        let out = smoother.smooth(vec![create_rate_stat("READ", 30.0)]);
+        assert_eq!(out[0].iops, 20.0); // mean of 10, 20, 30
+
+        // A fourth sample pushes the oldest (10.0) out of the window.
+        let out = smoother.smooth(vec![create_rate_stat("READ", 60.0)]);
+        assert_eq!(out[0].iops, (20.0 + 30.0 + 60.0) / 3.0);
+    }
+
+    #[test]
+    fn test_rate_smoother_new_operation_seeded_with_raw_sample() {
+        let mut smoother = RateSmoother::new(SmoothingMode::Ewma(4));
+        let out = smoother.smooth(vec![create_rate_stat("WRITE", 42.0)]);
+        assert_eq!(out[0].iops, 42.0);
+    }
+
+    #[test]
+    fn test_rate_smoother_ewma_recurrence() {
+        let mut smoother = RateSmoother::new(SmoothingMode::Ewma(4)); // alpha = 2/5 = 0.4
+        smoother.smooth(vec![create_rate_stat("READ", 100.0)]);
+        let out = smoother.smooth(vec![create_rate_stat("READ", 0.0)]);
+        // smoothed = 0.4 * 0 + 0.6 * 100 = 60.0
+        assert_eq!(out[0].iops, 60.0);
+    }
+
+    #[test]
+    fn test_rate_smoother_ages_out_missing_operations() {
+        let mut smoother = RateSmoother::new(SmoothingMode::Window(3));
+        smoother.smooth(vec![create_rate_stat("READ", 10.0), create_rate_stat("WRITE", 5.0)]);
+
+        // READ drops out of this interval's stats entirely.
+        smoother.smooth(vec![create_rate_stat("WRITE", 5.0)]);
+
+        // READ reappears: since its prior state aged out, it should be
+        // seeded fresh rather than averaged against the earlier 10.0 sample.
+        let out = smoother.smooth(vec![create_rate_stat("READ", 99.0)]);
+        assert_eq!(out[0].iops, 99.0);
+    }
+
     #[test]
     fn test_filter_operations() {
         let stats = vec![
@@ -214,12 +988,14 @@ mod tests {
                 delta_queue: 0,
                 delta_errors: 0,
                 delta_retrans: 0,
+                delta_ntrans: 0,
                 avg_rtt: 0.0,
                 avg_exec: 0.0,
                 avg_queue: 0.0,
                 kb_per_op: 0.0,
                 kb_per_sec: 0.0,
                 iops: 100.0,
+                reset_detected: false,
             },
             DeltaStats {
                 operation: "WRITE".to_string(),
@@ -232,25 +1008,162 @@ mod tests {
                 delta_queue: 0,
                 delta_errors: 0,
                 delta_retrans: 0,
+                delta_ntrans: 0,
                 avg_rtt: 0.0,
                 avg_exec: 0.0,
                 avg_queue: 0.0,
                 kb_per_op: 0.0,
                 kb_per_sec: 0.0,
                 iops: 50.0,
+                reset_detected: false,
             },
         ];
 
         // Test empty filter (should return all)
-        let empty_filter = HashSet::new();
+        let empty_filter = OperationSelector::Names(HashSet::new());
         let filtered = filter_operations(stats.clone(), &empty_filter);
         assert_eq!(filtered.len(), 2);
 
         // Test specific filter
-        let mut filter = HashSet::new();
-        filter.insert("READ".to_string());
-        let filtered = filter_operations(stats, &filter);
+        let mut names = HashSet::new();
+        names.insert("READ".to_string());
+        let filtered = filter_operations(stats, &OperationSelector::Names(names));
         assert_eq!(filtered.len(), 1);
         assert_eq!(filtered[0].operation, "READ");
     }
+
+    #[test]
+    fn test_filter_operations_glob() {
+        let stats = vec![
+            create_rate_stat("READ", 10.0),
+            create_rate_stat("READ_PLUS", 20.0),
+            create_rate_stat("WRITE", 30.0),
+            create_rate_stat("GETATTR", 40.0),
+        ];
+
+        let selector = OperationSelector::Globs(vec!["READ*".to_string(), "*ATTR".to_string()]);
+        let filtered = filter_operations(stats, &selector);
+
+        let names: Vec<&str> = filtered.iter().map(|s| s.operation.as_str()).collect();
+        assert_eq!(names, vec!["GETATTR", "READ", "READ_PLUS"]);
+    }
+
+    #[test]
+    fn test_filter_operations_top_n_by_iops_then_alphabetical() {
+        let stats = vec![
+            create_rate_stat("READ", 10.0),
+            create_rate_stat("WRITE", 50.0),
+            create_rate_stat("GETATTR", 30.0),
+            create_rate_stat("LOOKUP", 5.0),
+        ];
+
+        let selector = OperationSelector::TopN {
+            metric: crate::alerts::ThresholdMetric::Iops,
+            n: 3,
+        };
+        let filtered = filter_operations(stats, &selector);
+
+        // Top 3 by iops are WRITE(50), GETATTR(30), READ(10); the lowest
+        // (LOOKUP, 5) is dropped. The survivors are then alphabetized.
+        let names: Vec<&str> = filtered.iter().map(|s| s.operation.as_str()).collect();
+        assert_eq!(names, vec!["GETATTR", "READ", "WRITE"]);
+    }
+
+    fn create_test_mount_at(mount_point: &str, operations: HashMap<String, NFSOperation>) -> NFSMount {
+        NFSMount {
+            mount_point: mount_point.to_string(),
+            ..create_test_mount(operations)
+        }
+    }
+
+    #[test]
+    fn test_diff_computes_rates_for_matched_mounts_and_operations() {
+        let mut prev_ops = HashMap::new();
+        prev_ops.insert("READ".to_string(), create_test_operation("READ", 100, 1024, 2048, 1000, 2000));
+
+        let mut curr_ops = HashMap::new();
+        curr_ops.insert("READ".to_string(), create_test_operation("READ", 200, 2048, 4096, 2000, 4000));
+
+        let previous: HashMap<String, NFSMount> =
+            [("/mnt/nfs".to_string(), create_test_mount_at("/mnt/nfs", prev_ops))]
+                .into_iter()
+                .collect();
+        let current: HashMap<String, NFSMount> =
+            [("/mnt/nfs".to_string(), create_test_mount_at("/mnt/nfs", curr_ops))]
+                .into_iter()
+                .collect();
+
+        let deltas = diff(&previous, &current, Duration::from_secs(1));
+
+        assert_eq!(deltas.len(), 1);
+        assert_eq!(deltas[0].mount_point, "/mnt/nfs");
+        assert_eq!(deltas[0].operations.len(), 1);
+        assert_eq!(deltas[0].operations[0].operation, "READ");
+        assert_eq!(deltas[0].operations[0].delta_ops, 100);
+        assert_eq!(deltas[0].operations[0].iops, 100.0);
+    }
+
+    #[test]
+    fn test_diff_skips_mount_present_in_only_one_snapshot() {
+        let previous: HashMap<String, NFSMount> = HashMap::new();
+        let current: HashMap<String, NFSMount> =
+            [("/mnt/new".to_string(), create_test_mount_at("/mnt/new", HashMap::new()))]
+                .into_iter()
+                .collect();
+
+        let deltas = diff(&previous, &current, Duration::from_secs(1));
+        assert!(deltas.is_empty());
+    }
+
+    #[test]
+    fn test_diff_skips_operation_present_in_only_one_snapshot() {
+        let prev_ops = HashMap::new();
+        let mut curr_ops = HashMap::new();
+        curr_ops.insert("WRITE".to_string(), create_test_operation("WRITE", 50, 512, 0, 500, 1000));
+
+        let previous: HashMap<String, NFSMount> =
+            [("/mnt/nfs".to_string(), create_test_mount_at("/mnt/nfs", prev_ops))]
+                .into_iter()
+                .collect();
+        let current: HashMap<String, NFSMount> =
+            [("/mnt/nfs".to_string(), create_test_mount_at("/mnt/nfs", curr_ops))]
+                .into_iter()
+                .collect();
+
+        let deltas = diff(&previous, &current, Duration::from_secs(1));
+
+        assert_eq!(deltas.len(), 1);
+        assert!(deltas[0].operations.is_empty());
+    }
+
+    #[test]
+    fn test_diff_clamps_negative_delta_to_zero_on_reset() {
+        let mut prev_ops = HashMap::new();
+        prev_ops.insert("READ".to_string(), create_test_operation("READ", 500, 4096, 8192, 5000, 10000));
+
+        let mut curr_ops = HashMap::new();
+        curr_ops.insert("READ".to_string(), create_test_operation("READ", 10, 100, 200, 50, 100));
+
+        let previous: HashMap<String, NFSMount> =
+            [("/mnt/nfs".to_string(), create_test_mount_at("/mnt/nfs", prev_ops))]
+                .into_iter()
+                .collect();
+        let current: HashMap<String, NFSMount> =
+            [("/mnt/nfs".to_string(), create_test_mount_at("/mnt/nfs", curr_ops))]
+                .into_iter()
+                .collect();
+
+        let deltas = diff(&previous, &current, Duration::from_secs(1));
+
+        // A remount between snapshots drops every counter below its previous
+        // value; diff() must clamp to zero rather than reconstruct a
+        // wrapped/reset delta the way the live loop's counter_delta does,
+        // but the reset op still has to show up with reset_detected set
+        // rather than being silently dropped, or the clamp is unobservable.
+        assert_eq!(deltas.len(), 1);
+        assert_eq!(deltas[0].operations.len(), 1);
+        assert_eq!(deltas[0].operations[0].operation, "READ");
+        assert_eq!(deltas[0].operations[0].delta_ops, 0);
+        assert!(deltas[0].operations[0].reset_detected);
+    }
 }
\ No newline at end of file
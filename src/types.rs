@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -77,6 +77,198 @@ pub struct NFSMount {
     pub events: Option<NFSEvents>,
     pub bytes_read: i64,
     pub bytes_write: i64,
+    pub direct_bytes_read: i64,
+    pub direct_bytes_write: i64,
+    pub server_bytes_read: i64,
+    pub server_bytes_write: i64,
+    pub read_pages: i64,
+    pub write_pages: i64,
+    pub mount_addr: Option<String>,
+    pub server_caps: NFSServerCaps,
+    pub nfs_version: Option<String>,
+    pub proto: Option<String>,
+    pub xprt_proto: Option<String>,
+    pub xprt_sends: i64,
+    pub xprt_bklog_u: i64,
+    pub xprt_retrans: i64,
+    pub transport: Option<NFSTransport>,
+    pub options: Option<NFSMountOptions>,
+    pub fstype: Option<String>,
+    pub statvers: MountstatsVersion,
+}
+
+/// The mountstats schema version reported on the device line's
+/// `statvers=major.minor` token. Newer kernels add fields to existing
+/// lines (pNFS event counters, per-operation errors), so parsing code that
+/// needs to tell "field genuinely absent" from "wrong line" can consult this
+/// instead of guessing from field count alone.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MountstatsVersion {
+    #[default]
+    Unknown,
+    Known(u32, u32),
+}
+
+impl MountstatsVersion {
+    /// Parse a `statvers=` value like `"1.1"`. Falls back to `Unknown` on
+    /// anything that doesn't look like `major.minor`, rather than erroring.
+    pub fn parse(value: &str) -> Self {
+        match value.split_once('.') {
+            Some((major, minor)) => match (major.parse(), minor.parse()) {
+                (Ok(major), Ok(minor)) => MountstatsVersion::Known(major, minor),
+                _ => MountstatsVersion::Unknown,
+            },
+            None => MountstatsVersion::Unknown,
+        }
+    }
+
+    /// True if this schema version is known to be at least `major.minor`.
+    pub fn at_least(&self, major: u32, minor: u32) -> bool {
+        matches!(self, MountstatsVersion::Known(maj, min) if (*maj, *min) >= (major, minor))
+    }
+}
+
+/// Structured decode of the `opts:` mount-options line. Recognized
+/// `key=value` tokens land in their named field; anything this type doesn't
+/// know about falls back to `extra` rather than being dropped or erroring.
+/// Valueless tokens (`rw`, `hard`, `noatime`) land in `flags`.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct NFSMountOptions {
+    pub version: Option<String>,
+    pub rsize: Option<i64>,
+    pub wsize: Option<i64>,
+    pub timeo: Option<i64>,
+    pub retrans: Option<i64>,
+    pub proto: Option<String>,
+    pub sec: Option<String>,
+    pub flags: HashSet<String>,
+    pub extra: HashMap<String, String>,
+}
+
+/// Bitmask of negotiated NFS server capabilities, as reported on mountstats'
+/// `caps:` line. Mirrors the kernel's `NFS_CAP_*` flags (see
+/// `include/linux/nfs_fs_sb.h`) so the raw bitmask can be decoded into the
+/// same names operators already know from `nfsstat`/`nfsiostat` docs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct NFSServerCaps(pub u32);
+
+impl NFSServerCaps {
+    pub const READDIRPLUS: u32 = 1 << 0;
+    pub const HARDLINKS: u32 = 1 << 1;
+    pub const SYMLINKS: u32 = 1 << 2;
+    pub const ACLS: u32 = 1 << 3;
+    pub const ATOMIC_OPEN: u32 = 1 << 4;
+    pub const LGOPEN: u32 = 1 << 5;
+    pub const FILEID: u32 = 1 << 6;
+    pub const MODE: u32 = 1 << 7;
+    pub const NLINK: u32 = 1 << 8;
+    pub const OWNER: u32 = 1 << 9;
+    pub const OWNER_GROUP: u32 = 1 << 10;
+    pub const ATIME: u32 = 1 << 11;
+    pub const CTIME: u32 = 1 << 12;
+    pub const MTIME: u32 = 1 << 13;
+    pub const POSIX_LOCK: u32 = 1 << 14;
+    pub const UIDGID_NOMAP: u32 = 1 << 15;
+    pub const STATEID_NFSV41: u32 = 1 << 16;
+    pub const ATOMIC_OPEN_V1: u32 = 1 << 17;
+    pub const SECURITY_LABEL: u32 = 1 << 18;
+    pub const SEEK: u32 = 1 << 19;
+    pub const ALLOCATE: u32 = 1 << 20;
+    pub const DEALLOCATE: u32 = 1 << 21;
+    pub const LAYOUTSTATS: u32 = 1 << 22;
+    pub const CLONE: u32 = 1 << 23;
+    pub const COPY: u32 = 1 << 24;
+    pub const OFFLOAD_CANCEL: u32 = 1 << 25;
+
+    /// All flags this type knows how to name, in bit order.
+    const NAMED: &'static [(u32, &'static str)] = &[
+        (Self::READDIRPLUS, "READDIRPLUS"),
+        (Self::HARDLINKS, "HARDLINKS"),
+        (Self::SYMLINKS, "SYMLINKS"),
+        (Self::ACLS, "ACLS"),
+        (Self::ATOMIC_OPEN, "ATOMIC_OPEN"),
+        (Self::LGOPEN, "LGOPEN"),
+        (Self::FILEID, "FILEID"),
+        (Self::MODE, "MODE"),
+        (Self::NLINK, "NLINK"),
+        (Self::OWNER, "OWNER"),
+        (Self::OWNER_GROUP, "OWNER_GROUP"),
+        (Self::ATIME, "ATIME"),
+        (Self::CTIME, "CTIME"),
+        (Self::MTIME, "MTIME"),
+        (Self::POSIX_LOCK, "POSIX_LOCK"),
+        (Self::UIDGID_NOMAP, "UIDGID_NOMAP"),
+        (Self::STATEID_NFSV41, "STATEID_NFSV41"),
+        (Self::ATOMIC_OPEN_V1, "ATOMIC_OPEN_V1"),
+        (Self::SECURITY_LABEL, "SECURITY_LABEL"),
+        (Self::SEEK, "SEEK"),
+        (Self::ALLOCATE, "ALLOCATE"),
+        (Self::DEALLOCATE, "DEALLOCATE"),
+        (Self::LAYOUTSTATS, "LAYOUTSTATS"),
+        (Self::CLONE, "CLONE"),
+        (Self::COPY, "COPY"),
+        (Self::OFFLOAD_CANCEL, "OFFLOAD_CANCEL"),
+    ];
+
+    pub fn contains(&self, flag: u32) -> bool {
+        self.0 & flag == flag
+    }
+
+    /// Iterate the set bits' `NFS_CAP_*` names, in bit order.
+    pub fn iter_names(&self) -> impl Iterator<Item = &'static str> + '_ {
+        Self::NAMED
+            .iter()
+            .filter(|(bit, _)| self.contains(*bit))
+            .map(|(_, name)| *name)
+    }
+
+    /// Render the set bits as their `NFS_CAP_*` names, comma-joined, for use
+    /// as a Prometheus label value (e.g. "READDIRPLUS,ACLS,SEEK").
+    pub fn names(&self) -> String {
+        self.iter_names().collect::<Vec<_>>().join(",")
+    }
+}
+
+/// Full decode of the `xprt:` RPC transport line. UDP is connectionless, so
+/// `connect_count`/`connect_idle_time`/`max_slots`/`sending_queue`/
+/// `pending_queue` are only reported by TCP and RDMA and are `None` here.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NFSTransport {
+    pub protocol: String,
+    pub port: i64,
+    pub bind_count: i64,
+    pub connect_count: Option<i64>,
+    pub connect_idle_time: Option<i64>,
+    pub idle_time: i64,
+    pub sends: i64,
+    pub receives: i64,
+    pub bad_xids: i64,
+    pub req_queue_time: i64,
+    pub backlog_queue_wait: i64,
+    pub max_slots: Option<i64>,
+    pub sending_queue: Option<i64>,
+    pub pending_queue: Option<i64>,
+}
+
+impl NFSTransport {
+    /// Average backlog queue depth sampled per send, over the life of the
+    /// mount (`backlog_queue_wait` is a running sum, not an interval delta).
+    pub fn avg_backlog_wait(&self) -> f64 {
+        if self.sends > 0 {
+            self.backlog_queue_wait as f64 / self.sends as f64
+        } else {
+            0.0
+        }
+    }
+
+    /// Fraction of sends with no matching receive: `(sends - receives) / sends`.
+    pub fn retransmit_ratio(&self) -> f64 {
+        if self.sends > 0 {
+            (self.sends - self.receives) as f64 / self.sends as f64
+        } else {
+            0.0
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -91,10 +283,30 @@ pub struct DeltaStats {
     pub delta_queue: i64,
     pub delta_errors: i64,
     pub delta_retrans: i64,
+    pub delta_ntrans: i64,
     pub avg_rtt: f64,
     pub avg_exec: f64,
     pub avg_queue: f64,
     pub kb_per_op: f64,
     pub kb_per_sec: f64,
     pub iops: f64,
+    /// True if any counter backing this interval's deltas went backwards
+    /// (remount/reboot reset or 32-bit wraparound) and was corrected rather
+    /// than reported as a raw negative delta.
+    pub reset_detected: bool,
+}
+
+/// Delta of `NFSEvents` counters between two samples, used for the
+/// attribute-cache efficiency view (`--attr`).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EventDeltaStats {
+    pub attr_invalidate: i64,
+    pub inode_revalidate: i64,
+    pub dentry_revalidate: i64,
+    pub data_invalidate: i64,
+    pub vfs_access: i64,
+    pub vfs_open: i64,
+    pub vfs_lookup: i64,
+    pub vfs_getdents: i64,
+    pub attr_cache_hit_pct: f64,
 }
\ No newline at end of file
@@ -1,4 +1,4 @@
-use nfs_gaze::{DeltaStats, NFSMount};
+use nfs_gaze::{DeltaStats, NFSMount, NFSServerCaps};
 use nfs_gaze::display::display_stats_simple;
 use std::collections::HashMap;
 use chrono::{Utc, TimeZone};
@@ -42,6 +42,24 @@ fn test_display_stats_simple_without_bandwidth() {
         events: None,
         bytes_read: 0,
         bytes_write: 0,
+        direct_bytes_read: 0,
+        direct_bytes_write: 0,
+        server_bytes_read: 0,
+        server_bytes_write: 0,
+        read_pages: 0,
+        write_pages: 0,
+        mount_addr: None,
+        server_caps: NFSServerCaps::default(),
+        nfs_version: None,
+        proto: None,
+        xprt_proto: None,
+        xprt_sends: 0,
+        xprt_bklog_u: 0,
+        xprt_retrans: 0,
+        transport: None,
+        options: None,
+        fstype: None,
+        statvers: nfs_gaze::types::MountstatsVersion::Unknown,
     };
 
     let stats = vec![
@@ -56,12 +74,14 @@ fn test_display_stats_simple_without_bandwidth() {
             delta_queue: 0,
             delta_errors: 0,
             delta_retrans: 0,
+            delta_ntrans: 0,
             avg_rtt: 1.5,
             avg_exec: 2.0,
             avg_queue: 0.0,
             kb_per_op: 10.24,
             kb_per_sec: 1024.0,
             iops: 100.0,
+            reset_detected: false,
         },
         DeltaStats {
             operation: "WRITE".to_string(),
@@ -74,12 +94,14 @@ fn test_display_stats_simple_without_bandwidth() {
             delta_queue: 0,
             delta_errors: 0,
             delta_retrans: 0,
+            delta_ntrans: 0,
             avg_rtt: 2.5,
             avg_exec: 3.0,
             avg_queue: 0.0,
             kb_per_op: 10.24,
             kb_per_sec: 512.0,
             iops: 50.0,
+            reset_detected: false,
         },
     ];
 
@@ -107,6 +129,24 @@ fn test_display_stats_simple_with_bandwidth() {
         events: None,
         bytes_read: 0,
         bytes_write: 0,
+        direct_bytes_read: 0,
+        direct_bytes_write: 0,
+        server_bytes_read: 0,
+        server_bytes_write: 0,
+        read_pages: 0,
+        write_pages: 0,
+        mount_addr: None,
+        server_caps: NFSServerCaps::default(),
+        nfs_version: None,
+        proto: None,
+        xprt_proto: None,
+        xprt_sends: 0,
+        xprt_bklog_u: 0,
+        xprt_retrans: 0,
+        transport: None,
+        options: None,
+        fstype: None,
+        statvers: nfs_gaze::types::MountstatsVersion::Unknown,
     };
 
     let stats = vec![
@@ -121,12 +161,14 @@ fn test_display_stats_simple_with_bandwidth() {
             delta_queue: 0,
             delta_errors: 0,
             delta_retrans: 0,
+            delta_ntrans: 0,
             avg_rtt: 1.5,
             avg_exec: 2.0,
             avg_queue: 0.0,
             kb_per_op: 10.24,
             kb_per_sec: 1024.0,
             iops: 100.0,
+            reset_detected: false,
         },
         DeltaStats {
             operation: "WRITE".to_string(),
@@ -139,12 +181,14 @@ fn test_display_stats_simple_with_bandwidth() {
             delta_queue: 0,
             delta_errors: 0,
             delta_retrans: 0,
+            delta_ntrans: 0,
             avg_rtt: 2.5,
             avg_exec: 3.0,
             avg_queue: 0.0,
             kb_per_op: 10.24,
             kb_per_sec: 512.0,
             iops: 50.0,
+            reset_detected: false,
         },
     ];
 
@@ -172,6 +216,24 @@ fn test_display_stats_simple_empty_stats() {
         events: None,
         bytes_read: 0,
         bytes_write: 0,
+        direct_bytes_read: 0,
+        direct_bytes_write: 0,
+        server_bytes_read: 0,
+        server_bytes_write: 0,
+        read_pages: 0,
+        write_pages: 0,
+        mount_addr: None,
+        server_caps: NFSServerCaps::default(),
+        nfs_version: None,
+        proto: None,
+        xprt_proto: None,
+        xprt_sends: 0,
+        xprt_bklog_u: 0,
+        xprt_retrans: 0,
+        transport: None,
+        options: None,
+        fstype: None,
+        statvers: nfs_gaze::types::MountstatsVersion::Unknown,
     };
 
     let stats: Vec<DeltaStats> = vec![];